@@ -151,6 +151,7 @@ fn testnet_genesis(
             // Assign network admin rights.
             key: Some(root_key),
         },
+        membership: Default::default(),
         transaction_payment: Default::default(),
     }
 }
\ No newline at end of file
@@ -7,12 +7,14 @@
 
 use std::sync::Arc;
 
+pub mod voting;
+
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
-use substrate_dev_runtime::{opaque::Block, AccountId, Balance, Index};
+use substrate_dev_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Index, Runtime};
 
 pub use sc_rpc_api::DenyUnsafe;
 
@@ -36,22 +38,28 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_voting_runtime_api::VotingApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+        pallet_voting::BoundedCallOf<Runtime>,
+        pallet_voting::DescriptionOf<Runtime>,
+        pallet_voting::OptionsOf<Runtime>,
+    >,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use voting::{Voting, VotingApiServer};
 
     let mut module = RpcModule::new(());
     let FullDeps { client, pool, deny_unsafe } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
-
-    // Extend this RPC with a custom API by using the following syntax.
-    // `YourRpcStruct` should have a reference to a client, which is needed
-    // to call into the runtime.
-    // `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient, ...)))?;`
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(Voting::new(client).into_rpc())?;
 
     Ok(module)
 }
\ No newline at end of file
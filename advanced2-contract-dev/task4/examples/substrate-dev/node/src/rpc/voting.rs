@@ -0,0 +1,225 @@
+//! JSON-RPC methods of the voting pallet.
+
+use std::{fmt::Debug, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use pallet_voting_runtime_api::{ProposalInfo, VotingApi as VotingRuntimeApi};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+/// Maximum number of proposals returned by a single `voting_listActive` call.
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Error code returned when a runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Proposal as returned by the voting RPC methods.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Proposal<AccountId, BlockNumber> {
+    /// Proposal ID.
+    pub id: u32,
+    /// Proposal creator.
+    pub proposer: AccountId,
+    /// Proposal description.
+    pub description: Bytes,
+    /// Voting options.
+    pub options: Vec<Bytes>,
+    /// Voting start block.
+    pub start_block: BlockNumber,
+    /// Voting end block.
+    pub end_block: BlockNumber,
+    /// Minimum total vote weight for the proposal to be decided.
+    pub quorum: NumberOrHex,
+    /// Share of the vote weight the winning option must reach, in parts per billion.
+    pub approval_threshold: u32,
+    /// Proposal status.
+    pub status: String,
+    /// How voters express their choice.
+    pub mode: String,
+    /// Whether votes are committed and revealed.
+    pub secret_ballot: bool,
+    /// Whether a call is dispatched when the proposal passes.
+    pub has_call: bool,
+    /// Amount reserved from the proposer.
+    pub deposit: NumberOrHex,
+}
+
+impl<AccountId, BlockNumber> Proposal<AccountId, BlockNumber> {
+    fn new<Balance, Call, Description, Options>(
+        id: u32,
+        info: ProposalInfo<AccountId, Balance, BlockNumber, Call, Description, Options>,
+    ) -> Self
+    where
+        Balance: Into<NumberOrHex>,
+        Description: Into<Vec<u8>>,
+        Options: IntoIterator,
+        Options::Item: Into<Vec<u8>>,
+    {
+        Self {
+            id,
+            proposer: info.proposer,
+            description: Bytes(info.description.into()),
+            options: info.options.into_iter().map(|option| Bytes(option.into())).collect(),
+            start_block: info.start_block,
+            end_block: info.end_block,
+            quorum: info.quorum.into(),
+            approval_threshold: info.approval_threshold.deconstruct(),
+            status: format!("{:?}", info.status),
+            mode: format!("{:?}", info.mode),
+            secret_ballot: info.secret_ballot,
+            has_call: info.call.is_some(),
+            deposit: info.deposit.into(),
+        }
+    }
+}
+
+/// Voting RPC methods.
+#[rpc(server)]
+pub trait VotingApi<BlockHash, AccountId, BlockNumber> {
+    /// Proposal with the given ID.
+    #[method(name = "voting_getProposal")]
+    fn get_proposal(
+        &self,
+        proposal_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Proposal<AccountId, BlockNumber>>>;
+
+    /// Vote weight of each option of a proposal.
+    #[method(name = "voting_getResults")]
+    fn get_results(&self, proposal_id: u32, at: Option<BlockHash>) -> RpcResult<Vec<NumberOrHex>>;
+
+    /// Active proposals with an ID in `start..start + limit`, `limit` capped at 100.
+    #[method(name = "voting_listActive")]
+    fn list_active(
+        &self,
+        start: Option<u32>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Proposal<AccountId, BlockNumber>>>;
+
+    /// Whether `who` has voted on a proposal.
+    #[method(name = "voting_hasVoted")]
+    fn has_voted(&self, proposal_id: u32, who: AccountId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// Proposals `who` has voted on, most recent first, 20 per page.
+    #[method(name = "voting_voterHistory")]
+    fn voter_history(
+        &self,
+        who: AccountId,
+        page: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u32>>;
+}
+
+/// Provides the voting RPC methods on top of the runtime API.
+pub struct Voting<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Voting<C, Block> {
+    /// Create a new instance of the voting RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+fn runtime_error(message: &'static str, error: impl Debug) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", error))))
+        .into()
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber, Call, Description, Options>
+    VotingApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for Voting<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: VotingRuntimeApi<Block, AccountId, Balance, BlockNumber, Call, Description, Options>,
+    AccountId: Codec + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+    BlockNumber: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    Call: Codec + Send + Sync + 'static,
+    Description: Codec + Into<Vec<u8>> + Send + Sync + 'static,
+    Options: Codec + IntoIterator + Send + Sync + 'static,
+    Options::Item: Into<Vec<u8>>,
+{
+    fn get_proposal(
+        &self,
+        proposal_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Proposal<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let proposal = api
+            .proposal(at_hash, proposal_id)
+            .map_err(|e| runtime_error("Unable to query proposal.", e))?;
+        Ok(proposal.map(|info| Proposal::new(proposal_id, info)))
+    }
+
+    fn get_results(
+        &self,
+        proposal_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let results = api
+            .results(at_hash, proposal_id)
+            .map_err(|e| runtime_error("Unable to query results.", e))?;
+        Ok(results.into_iter().map(Into::into).collect())
+    }
+
+    fn list_active(
+        &self,
+        start: Option<u32>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Proposal<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let limit = limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+
+        let proposals = api
+            .active_proposals(at_hash, start.unwrap_or_default(), limit)
+            .map_err(|e| runtime_error("Unable to query active proposals.", e))?;
+        Ok(proposals.into_iter().map(|(id, info)| Proposal::new(id, info)).collect())
+    }
+
+    fn has_voted(
+        &self,
+        proposal_id: u32,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.has_voted(at_hash, proposal_id, who)
+            .map_err(|e| runtime_error("Unable to query vote.", e))
+    }
+
+    fn voter_history(
+        &self,
+        who: AccountId,
+        page: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u32>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.voter_history(at_hash, who, page.unwrap_or_default())
+            .map_err(|e| runtime_error("Unable to query voter history.", e))
+    }
+}
@@ -0,0 +1,21 @@
+//! Runtime API definition for the asset registry pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_asset_registry::StatusChange;
+
+sp_api::decl_runtime_apis! {
+    /// Read access to the moderation trail of the asset registry pallet
+    pub trait AssetRegistryApi<AccountId, BlockNumber, Reason>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Reason: Codec,
+    {
+        /// Status changes of an asset, oldest first
+        fn status_history(asset_id: u32) -> Vec<StatusChange<AccountId, BlockNumber, Reason>>;
+    }
+}
@@ -5,7 +5,44 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as AssetRegistry;
 use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Gives `who` enough to hold the deposit of the largest possible asset
+fn fund<T: Config>(who: &T::AccountId) {
+    let bytes = T::MaxAssetNameLength::get()
+        .saturating_add(T::MaxAssetDescriptionLength::get())
+        .saturating_add(T::MaxAssetMetadataLength::get());
+    let deposit = T::RegistrationDepositPerByte::get()
+        .saturating_mul(bytes.into())
+        .saturating_add(T::RegistrationDeposit::get());
+    T::Currency::set_balance(
+        who,
+        T::Currency::minimum_balance().saturating_add(deposit.saturating_mul(10u32.into())),
+    );
+}
+
+/// Registers and approves a mintable, burnable and transferable asset owned by `owner`
+fn approved_asset<T: Config>(owner: &T::AccountId) -> Result<(), BenchmarkError> {
+    fund::<T>(owner);
+    AssetRegistry::<T>::register_asset(
+        RawOrigin::Signed(owner.clone()).into(),
+        b"Test Token".to_vec(),
+        b"TEST".to_vec(),
+        b"Description".to_vec(),
+        18,
+        1000,
+        b"{}".to_vec(),
+        true,
+        true,
+        true,
+    )?;
+    let origin =
+        T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    AssetRegistry::<T>::approve_asset(origin, 0)?;
+    Ok(())
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -14,6 +51,7 @@ mod benchmarks {
     #[benchmark]
     fn register_asset() {
         let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
         let name = b"Test Token for Benchmarking".to_vec();
         let symbol = b"BENCH".to_vec();
         let description = b"A test token for benchmarking purposes".to_vec();
@@ -40,9 +78,11 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn approve_asset() {
-        let caller: T::AccountId = whitelisted_caller();
+    fn approve_asset() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
         let owner: T::AccountId = account("owner", 0, 0);
+        fund::<T>(&owner);
         
         // Setup: register an asset first
         let _ = AssetRegistry::<T>::register_asset(
@@ -59,16 +99,20 @@ mod benchmarks {
         );
 
         #[extrinsic_call]
-        approve_asset(RawOrigin::Signed(caller), 0);
+        approve_asset(origin as T::RuntimeOrigin, 0);
 
         let asset = AssetRegistry::<T>::assets(0).unwrap();
         assert_eq!(asset.status, AssetStatus::Approved);
+        assert!(asset.deposit.is_zero());
+        Ok(())
     }
 
     #[benchmark]
-    fn reject_asset() {
-        let caller: T::AccountId = whitelisted_caller();
+    fn reject_asset() -> Result<(), BenchmarkError> {
+        let origin =
+            T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
         let owner: T::AccountId = account("owner", 0, 0);
+        fund::<T>(&owner);
         
         // Setup: register an asset first
         let _ = AssetRegistry::<T>::register_asset(
@@ -87,17 +131,20 @@ mod benchmarks {
         let reason = b"Invalid asset for benchmarking".to_vec();
 
         #[extrinsic_call]
-        reject_asset(RawOrigin::Signed(caller), 0, reason);
+        reject_asset(origin as T::RuntimeOrigin, 0, reason);
 
         let asset = AssetRegistry::<T>::assets(0).unwrap();
         assert_eq!(asset.status, AssetStatus::Rejected);
+        assert!(asset.deposit.is_zero());
+        Ok(())
     }
 
     #[benchmark]
-    fn suspend_asset() {
-        let caller: T::AccountId = whitelisted_caller();
+    fn suspend_asset() -> Result<(), BenchmarkError> {
+        let origin =
+            T::SuspendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
         let owner: T::AccountId = account("owner", 0, 0);
-        let approver: T::AccountId = account("approver", 0, 1);
+        fund::<T>(&owner);
         
         // Setup: register and approve an asset first
         let _ = AssetRegistry::<T>::register_asset(
@@ -113,23 +160,73 @@ mod benchmarks {
             true,
         );
 
-        let _ = AssetRegistry::<T>::approve_asset(
-            RawOrigin::Signed(approver).into(),
-            0,
-        );
+        let approve_origin =
+            T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        AssetRegistry::<T>::approve_asset(approve_origin, 0)?;
 
         let reason = b"Suspicious activity detected".to_vec();
 
         #[extrinsic_call]
-        suspend_asset(RawOrigin::Signed(caller), 0, reason);
+        suspend_asset(origin as T::RuntimeOrigin, 0, reason);
 
         let asset = AssetRegistry::<T>::assets(0).unwrap();
         assert_eq!(asset.status, AssetStatus::Suspended);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reinstate_asset() -> Result<(), BenchmarkError> {
+        let origin =
+            T::SuspendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        approved_asset::<T>(&owner)?;
+        let suspend_origin =
+            T::SuspendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        AssetRegistry::<T>::suspend_asset(suspend_origin, 0, b"Suspicious".to_vec())?;
+
+        let reason = vec![b'r'; T::MaxReasonLength::get() as usize];
+
+        #[extrinsic_call]
+        reinstate_asset(origin as T::RuntimeOrigin, 0, reason);
+
+        let asset = AssetRegistry::<T>::assets(0).unwrap();
+        assert_eq!(asset.status, AssetStatus::Approved);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn resubmit_asset() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        AssetRegistry::<T>::register_asset(
+            RawOrigin::Signed(caller.clone()).into(),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        )?;
+        let reject_origin =
+            T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        AssetRegistry::<T>::reject_asset(reject_origin, 0, b"Invalid".to_vec())?;
+
+        #[extrinsic_call]
+        resubmit_asset(RawOrigin::Signed(caller), 0);
+
+        let asset = AssetRegistry::<T>::assets(0).unwrap();
+        assert_eq!(asset.status, AssetStatus::Pending);
+        assert!(!asset.deposit.is_zero());
+        Ok(())
     }
 
     #[benchmark]
     fn update_asset_metadata() {
         let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
         
         // Setup: register an asset first
         let _ = AssetRegistry::<T>::register_asset(
@@ -155,9 +252,10 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn transfer_ownership() {
+    fn propose_ownership_transfer() {
         let caller: T::AccountId = whitelisted_caller();
         let new_owner: T::AccountId = account("new_owner", 0, 0);
+        fund::<T>(&caller);
         
         // Setup: register an asset first
         let _ = AssetRegistry::<T>::register_asset(
@@ -174,12 +272,186 @@ mod benchmarks {
         );
 
         #[extrinsic_call]
-        transfer_ownership(RawOrigin::Signed(caller.clone()), 0, new_owner.clone());
+        propose_ownership_transfer(RawOrigin::Signed(caller), 0, new_owner.clone());
+
+        let transfer = AssetRegistry::<T>::pending_ownership_transfer(0).unwrap();
+        assert_eq!(transfer.new_owner, new_owner);
+    }
+
+    #[benchmark]
+    fn accept_ownership() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&owner);
+        fund::<T>(&caller);
+
+        // Setup: register a pending asset, so the deposit moves too
+        AssetRegistry::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        )?;
+        AssetRegistry::<T>::propose_ownership_transfer(
+            RawOrigin::Signed(owner.clone()).into(),
+            0,
+            caller.clone(),
+        )?;
+
+        #[extrinsic_call]
+        accept_ownership(RawOrigin::Signed(caller.clone()), 0);
 
         let asset = AssetRegistry::<T>::assets(0).unwrap();
-        assert_eq!(asset.owner, new_owner);
-        assert_eq!(AssetRegistry::<T>::asset_count_by_owner(&caller), 0);
-        assert_eq!(AssetRegistry::<T>::asset_count_by_owner(&new_owner), 1);
+        assert_eq!(asset.owner, caller);
+        assert_eq!(AssetRegistry::<T>::asset_count_by_owner(&owner), 0);
+        assert_eq!(AssetRegistry::<T>::asset_count_by_owner(&caller), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_ownership_transfer() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+        fund::<T>(&caller);
+
+        AssetRegistry::<T>::register_asset(
+            RawOrigin::Signed(caller.clone()).into(),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        )?;
+        AssetRegistry::<T>::propose_ownership_transfer(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            new_owner,
+        )?;
+
+        #[extrinsic_call]
+        cancel_ownership_transfer(RawOrigin::Signed(caller), 0);
+
+        assert!(AssetRegistry::<T>::pending_ownership_transfer(0).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_expired_ownership_transfer() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("owner", 0, 0);
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+        fund::<T>(&owner);
+
+        AssetRegistry::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        )?;
+        AssetRegistry::<T>::propose_ownership_transfer(
+            RawOrigin::Signed(owner).into(),
+            0,
+            new_owner,
+        )?;
+        let expires_at = AssetRegistry::<T>::pending_ownership_transfer(0).unwrap().expires_at;
+        frame_system::Pallet::<T>::set_block_number(expires_at.saturating_add(1u32.into()));
+
+        #[extrinsic_call]
+        remove_expired_ownership_transfer(RawOrigin::Signed(caller), 0);
+
+        assert!(AssetRegistry::<T>::pending_ownership_transfer(0).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn mint() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        approved_asset::<T>(&caller)?;
+
+        #[extrinsic_call]
+        mint(RawOrigin::Signed(caller), 0, beneficiary.clone(), 500);
+
+        assert_eq!(AssetRegistry::<T>::balance_of(0, &beneficiary), 500);
+        assert_eq!(AssetRegistry::<T>::assets(0).unwrap().total_supply, 1500);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn burn() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        approved_asset::<T>(&caller)?;
+
+        #[extrinsic_call]
+        burn(RawOrigin::Signed(caller.clone()), 0, 400);
+
+        assert_eq!(AssetRegistry::<T>::balance_of(0, &caller), 600);
+        assert_eq!(AssetRegistry::<T>::assets(0).unwrap().total_supply, 600);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("dest", 0, 0);
+        approved_asset::<T>(&caller)?;
+
+        #[extrinsic_call]
+        transfer(RawOrigin::Signed(caller.clone()), 0, dest.clone(), 400);
+
+        assert_eq!(AssetRegistry::<T>::balance_of(0, &caller), 600);
+        assert_eq!(AssetRegistry::<T>::balance_of(0, &dest), 400);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let spender: T::AccountId = account("spender", 0, 0);
+        approved_asset::<T>(&caller)?;
+
+        #[extrinsic_call]
+        approve(RawOrigin::Signed(caller.clone()), 0, spender.clone(), 400);
+
+        assert_eq!(AssetRegistry::<T>::allowance((0, &caller, &spender)), 400);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_from() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("dest", 0, 0);
+        approved_asset::<T>(&owner)?;
+        AssetRegistry::<T>::approve(
+            RawOrigin::Signed(owner.clone()).into(),
+            0,
+            caller.clone(),
+            500,
+        )?;
+
+        #[extrinsic_call]
+        transfer_from(RawOrigin::Signed(caller.clone()), 0, owner.clone(), dest.clone(), 400);
+
+        assert_eq!(AssetRegistry::<T>::balance_of(0, &dest), 400);
+        assert_eq!(AssetRegistry::<T>::allowance((0, &owner, &caller)), 100);
+        Ok(())
     }
 
     impl_benchmark_test_suite!(AssetRegistry, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,188 @@
+//! Implementations of the `fungibles` traits over the asset balance ledger.
+
+use super::*;
+use frame_support::{
+    ensure,
+    traits::tokens::{
+        fungibles::{Dust, Inspect, Mutate, Unbalanced},
+        DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence,
+    },
+};
+use sp_runtime::{DispatchError, TokenError};
+
+impl<T: Config> Pallet<T> {
+    /// Whether the asset has a balance ledger, i.e. it is approved or suspended
+    fn has_ledger(asset_id: u32) -> bool {
+        Self::assets(&asset_id).map_or(false, |asset| {
+            matches!(asset.status, AssetStatus::Approved | AssetStatus::Suspended)
+        })
+    }
+
+    /// Remove `amount` of a burnable asset from `who` and from the total issuance
+    ///
+    /// Burnable assets can be burned even if they are not transferable.
+    fn burn_balance(
+        asset_id: u32,
+        who: &T::AccountId,
+        amount: u128,
+        precision: Precision,
+    ) -> Result<u128, DispatchError> {
+        let info = Self::assets(&asset_id).ok_or(TokenError::UnknownAsset)?;
+        ensure!(info.status == AssetStatus::Approved, TokenError::UnknownAsset);
+        ensure!(info.is_burnable, TokenError::Blocked);
+
+        let balance = Self::balance_of(asset_id, who);
+        let actual = match precision {
+            Precision::BestEffort => amount.min(balance),
+            Precision::Exact => {
+                ensure!(balance >= amount, TokenError::FundsUnavailable);
+                amount
+            },
+        };
+        Self::set_balance(asset_id, who, balance - actual);
+        Self::set_total_issuance(asset_id, info.total_supply.saturating_sub(actual));
+        Ok(actual)
+    }
+}
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type AssetId = u32;
+    type Balance = u128;
+
+    fn total_issuance(asset: u32) -> u128 {
+        if Self::has_ledger(asset) {
+            Self::assets(&asset).map_or(0, |asset| asset.total_supply)
+        } else {
+            0
+        }
+    }
+
+    fn minimum_balance(_asset: u32) -> u128 {
+        0
+    }
+
+    fn total_balance(asset: u32, who: &T::AccountId) -> u128 {
+        Self::balance_of(asset, who)
+    }
+
+    fn balance(asset: u32, who: &T::AccountId) -> u128 {
+        Self::balance_of(asset, who)
+    }
+
+    fn reducible_balance(
+        asset: u32,
+        who: &T::AccountId,
+        _preservation: Preservation,
+        _force: Fortitude,
+    ) -> u128 {
+        // Balances of assets which are not transferable can only be burned
+        match Self::assets(&asset) {
+            Some(info) if info.is_transferable => Self::approved_balance(asset, who),
+            _ => 0,
+        }
+    }
+
+    fn can_deposit(
+        asset: u32,
+        who: &T::AccountId,
+        amount: u128,
+        provenance: Provenance,
+    ) -> DepositConsequence {
+        let Some(info) = Self::assets(&asset) else { return DepositConsequence::UnknownAsset };
+        if info.status != AssetStatus::Approved {
+            return DepositConsequence::UnknownAsset
+        }
+        if provenance == Provenance::Minted {
+            if !info.is_mintable {
+                return DepositConsequence::Blocked
+            }
+            if info.total_supply.checked_add(amount).is_none() {
+                return DepositConsequence::Overflow
+            }
+        }
+        if Self::balance_of(asset, who).checked_add(amount).is_none() {
+            return DepositConsequence::Overflow
+        }
+        DepositConsequence::Success
+    }
+
+    fn can_withdraw(asset: u32, who: &T::AccountId, amount: u128) -> WithdrawConsequence<u128> {
+        let Some(info) = Self::assets(&asset) else { return WithdrawConsequence::UnknownAsset };
+        if info.status != AssetStatus::Approved {
+            return WithdrawConsequence::UnknownAsset
+        }
+        if !info.is_transferable {
+            return WithdrawConsequence::Frozen
+        }
+        if Self::balance_of(asset, who) < amount {
+            return WithdrawConsequence::BalanceLow
+        }
+        WithdrawConsequence::Success
+    }
+
+    fn asset_exists(asset: u32) -> bool {
+        Self::has_ledger(asset)
+    }
+}
+
+impl<T: Config> Unbalanced<T::AccountId> for Pallet<T> {
+    fn handle_dust(_dust: Dust<T::AccountId, Self>) {
+        // The minimum balance is zero, so there is never any dust.
+    }
+
+    fn write_balance(
+        asset: u32,
+        who: &T::AccountId,
+        amount: u128,
+    ) -> Result<Option<u128>, DispatchError> {
+        ensure!(Self::is_asset_approved(asset), Error::<T>::AssetNotApproved);
+        Self::set_balance(asset, who, amount);
+        Ok(None)
+    }
+
+    fn set_total_issuance(asset: u32, amount: u128) {
+        Assets::<T>::mutate(&asset, |maybe_asset| {
+            if let Some(info) = maybe_asset {
+                info.total_supply = amount;
+            }
+        });
+    }
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+    fn mint_into(asset: u32, who: &T::AccountId, amount: u128) -> Result<u128, DispatchError> {
+        // Unlike the default, refuse to mint assets which are not mintable
+        Self::can_deposit(asset, who, amount, Provenance::Minted).into_result()?;
+        let actual = Self::increase_balance(asset, who, amount, Precision::Exact)?;
+        Self::set_total_issuance(asset, Self::total_issuance(asset).saturating_add(actual));
+        Self::done_mint_into(asset, who, amount);
+        Ok(actual)
+    }
+
+    fn burn_from(
+        asset: u32,
+        who: &T::AccountId,
+        amount: u128,
+        precision: Precision,
+        _force: Fortitude,
+    ) -> Result<u128, DispatchError> {
+        // Unlike the default, refuse to burn assets which are not burnable
+        let actual = Self::burn_balance(asset, who, amount, precision)?;
+        Self::done_burn_from(asset, who, actual);
+        Ok(actual)
+    }
+
+    fn shelve(asset: u32, who: &T::AccountId, amount: u128) -> Result<u128, DispatchError> {
+        let actual = Self::burn_balance(asset, who, amount, Precision::Exact)?;
+        Self::done_shelve(asset, who, actual);
+        Ok(actual)
+    }
+
+    fn restore(asset: u32, who: &T::AccountId, amount: u128) -> Result<u128, DispatchError> {
+        Self::can_deposit(asset, who, amount, Provenance::Minted).into_result()?;
+        let actual = Self::increase_balance(asset, who, amount, Precision::Exact)?;
+        Self::set_total_issuance(asset, Self::total_issuance(asset).saturating_add(actual));
+        Self::done_restore(asset, who, amount);
+        Ok(actual)
+    }
+}
@@ -14,6 +14,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod fungibles;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
@@ -23,14 +25,42 @@ pub mod pallet {
     use frame_support::{
         dispatch::{DispatchResult, DispatchResultWithPostInfo},
         pallet_prelude::*,
-        traits::{Get, Randomness},
+        traits::{
+            fungible::{self, MutateHold},
+            tokens::{Fortitude, Precision},
+            Get, Randomness,
+        },
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{Saturating, Zero};
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Perbill,
+    };
     use sp_std::vec::Vec;
 
+    /// Notified whenever the balance an account holds of an approved asset changes
+    pub trait OnBalanceChange<AccountId> {
+        /// The balance of `who` in `asset_id` is about to change from `previous`
+        fn on_balance_change(asset_id: u32, who: &AccountId, previous: u128);
+    }
+
+    impl<AccountId> OnBalanceChange<AccountId> for () {
+        fn on_balance_change(_asset_id: u32, _who: &AccountId, _previous: u128) {}
+    }
+
+    /// The in-code storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Asset information as stored by the pallet
+    pub type AssetInfoOf<T> =
+        AssetInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
@@ -61,10 +91,71 @@ pub mod pallet {
         /// Maximum number of assets per owner
         #[pallet::constant]
         type MaxAssetsPerOwner: Get<u32>;
+
+        /// Handler notified of changes to the balances of approved assets
+        type OnBalanceChange: OnBalanceChange<Self::AccountId>;
+
+        /// Origin allowed to approve pending assets
+        type ApproveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to reject pending assets
+        type RejectOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to suspend approved assets and reinstate suspended ones
+        type SuspendOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Currency the registration deposit is held in
+        type Currency: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + fungible::Mutate<Self::AccountId>;
+
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Base deposit held while an asset is pending
+        #[pallet::constant]
+        type RegistrationDeposit: Get<BalanceOf<Self>>;
+
+        /// Deposit held per byte of the name, description and metadata of a pending asset
+        #[pallet::constant]
+        type RegistrationDepositPerByte: Get<BalanceOf<Self>>;
+
+        /// Portion of the deposit burned when an asset is rejected, the rest is refunded
+        #[pallet::constant]
+        type RejectionBurn: Get<Perbill>;
+
+        /// Number of blocks a proposed ownership transfer can be accepted for
+        #[pallet::constant]
+        type OwnershipTransferPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum length of the reason for rejecting, suspending or reinstating an asset
+        #[pallet::constant]
+        type MaxReasonLength: Get<u32>;
+
+        /// Maximum number of status changes kept per asset, the oldest are dropped first
+        #[pallet::constant]
+        type MaxStatusHistory: Get<u32>;
+    }
+
+    /// A reason for the pallet placing a hold on funds
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// The deposit of an asset pending approval
+        AssetRegistration,
+    }
+
+    /// Kind of origin an asset was approved, rejected or suspended by
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Moderator<AccountId> {
+        /// Root, e.g. sudo
+        Root,
+        /// A signed account allowed by the configured origin
+        Signed(AccountId),
+        /// Any other origin, e.g. a collective
+        Other,
     }
 
     /// Asset status enumeration
-    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub enum AssetStatus {
         /// Asset is pending approval
         Pending,
@@ -84,7 +175,7 @@ pub mod pallet {
 
     /// Asset information
     #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
-    pub struct AssetInfo<AccountId, BlockNumber> {
+    pub struct AssetInfo<AccountId, BlockNumber, Balance> {
         /// Asset owner
         pub owner: AccountId,
         /// Asset name
@@ -109,6 +200,39 @@ pub mod pallet {
         pub is_mintable: bool,
         /// Whether the asset is burnable
         pub is_burnable: bool,
+        /// Deposit held from the owner while the asset is pending
+        pub deposit: Balance,
+    }
+
+    /// Reason given for a change of asset status
+    pub type ReasonOf<T> = BoundedVec<u8, <T as Config>::MaxReasonLength>;
+
+    /// Entry of the status history of an asset
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct StatusChange<AccountId, BlockNumber, Reason> {
+        /// Block the status changed in
+        pub block: BlockNumber,
+        /// Status before the change
+        pub from: AssetStatus,
+        /// Status after the change
+        pub to: AssetStatus,
+        /// Kind of origin that changed the status, the owner for resubmissions
+        pub actor: Moderator<AccountId>,
+        /// Reason given for the change, empty for approvals and resubmissions
+        pub reason: Reason,
+    }
+
+    /// Status history entry as stored by the pallet
+    pub type StatusChangeOf<T> =
+        StatusChange<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, ReasonOf<T>>;
+
+    /// Ownership transfer proposed to a new owner
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct PendingTransfer<AccountId, BlockNumber> {
+        /// Account that can accept the asset
+        pub new_owner: AccountId,
+        /// Last block the transfer can be accepted in
+        pub expires_at: BlockNumber,
     }
 
     /// Storage for assets
@@ -118,7 +242,7 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         u32,
-        AssetInfo<T::AccountId, BlockNumberFor<T>>,
+        AssetInfoOf<T>,
         OptionQuery,
     >;
 
@@ -162,6 +286,55 @@ pub mod pallet {
     #[pallet::getter(fn next_asset_id)]
     pub type NextAssetId<T> = StorageValue<_, u32, ValueQuery>;
 
+    /// Status changes of each asset, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn status_history)]
+    pub type StatusHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32, // asset_id
+        BoundedVec<StatusChangeOf<T>, T::MaxStatusHistory>,
+        ValueQuery,
+    >;
+
+    /// Ownership transfers waiting for the new owner to accept
+    #[pallet::storage]
+    #[pallet::getter(fn pending_ownership_transfer)]
+    pub type PendingOwnershipTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32, // asset_id
+        PendingTransfer<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Balances of approved assets, the owner is credited the total supply on approval
+    #[pallet::storage]
+    #[pallet::getter(fn balance_of)]
+    pub type AssetBalances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32, // asset_id
+        Blake2_128Concat,
+        T::AccountId,
+        u128,
+        ValueQuery,
+    >;
+
+    /// Amounts spenders may transfer on behalf of holders with `transfer_from`
+    #[pallet::storage]
+    #[pallet::getter(fn allowance)]
+    pub type Allowances<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, u32>,          // asset_id
+            NMapKey<Blake2_128Concat, T::AccountId>, // owner
+            NMapKey<Blake2_128Concat, T::AccountId>, // spender
+        ),
+        u128,
+        ValueQuery,
+    >;
+
     /// Pallets use events to inform users when important changes are made.
     /// https://docs.substrate.io/main-docs/build/events-errors/
     #[pallet::event]
@@ -177,18 +350,18 @@ pub mod pallet {
         /// An asset has been approved
         AssetApproved {
             asset_id: u32,
-            approver: T::AccountId,
+            approver: Moderator<T::AccountId>,
         },
         /// An asset has been rejected
         AssetRejected {
             asset_id: u32,
-            rejector: T::AccountId,
+            rejector: Moderator<T::AccountId>,
             reason: Vec<u8>,
         },
         /// An asset has been suspended
         AssetSuspended {
             asset_id: u32,
-            suspender: T::AccountId,
+            suspender: Moderator<T::AccountId>,
             reason: Vec<u8>,
         },
         /// Asset metadata has been updated
@@ -196,12 +369,78 @@ pub mod pallet {
             asset_id: u32,
             updater: T::AccountId,
         },
-        /// Asset ownership has been transferred
+        /// Asset has been reinstated
+        AssetReinstated {
+            asset_id: u32,
+            reinstater: Moderator<T::AccountId>,
+            reason: Vec<u8>,
+        },
+        /// Rejected asset has been resubmitted for approval
+        AssetResubmitted {
+            asset_id: u32,
+            owner: T::AccountId,
+        },
+        /// A transfer of ownership has been proposed
+        OwnershipTransferProposed {
+            asset_id: u32,
+            from: T::AccountId,
+            to: T::AccountId,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// A proposed transfer of ownership has been cancelled
+        OwnershipTransferCancelled {
+            asset_id: u32,
+            to: T::AccountId,
+        },
+        /// An expired transfer of ownership has been removed
+        OwnershipTransferExpired {
+            asset_id: u32,
+            to: T::AccountId,
+        },
+        /// Asset ownership has been transferred to the account that accepted it
         AssetOwnershipTransferred {
             asset_id: u32,
             from: T::AccountId,
             to: T::AccountId,
         },
+        /// Units of an asset have been minted
+        Minted {
+            asset_id: u32,
+            to: T::AccountId,
+            amount: u128,
+        },
+        /// Units of an asset have been burned
+        Burned {
+            asset_id: u32,
+            from: T::AccountId,
+            amount: u128,
+        },
+        /// Units of an asset have been transferred
+        Transferred {
+            asset_id: u32,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: u128,
+        },
+        /// A spender has been allowed to transfer units on behalf of a holder
+        TransferApproved {
+            asset_id: u32,
+            owner: T::AccountId,
+            spender: T::AccountId,
+            amount: u128,
+        },
+        /// The registration deposit of an asset has been refunded
+        DepositRefunded {
+            asset_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Part of the registration deposit of a rejected asset has been burned
+        DepositSlashed {
+            asset_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -237,6 +476,36 @@ pub mod pallet {
         InvalidDecimals,
         /// Total supply is zero
         TotalSupplyZero,
+        /// Asset owners cannot moderate their own assets
+        CannotModerateOwnAsset,
+        /// Asset is not transferable
+        AssetNotTransferable,
+        /// Asset is not mintable
+        AssetNotMintable,
+        /// Asset is not burnable
+        AssetNotBurnable,
+        /// Balance too low for the operation
+        InsufficientBalance,
+        /// Amount exceeds the allowance of the spender
+        AllowanceExceeded,
+        /// Total supply would overflow
+        SupplyOverflow,
+        /// Account already owns the asset
+        AlreadyAssetOwner,
+        /// No ownership transfer is pending for the asset
+        NoPendingTransfer,
+        /// The ownership transfer was proposed to another account
+        NotPendingOwner,
+        /// The ownership transfer has expired
+        TransferExpired,
+        /// The ownership transfer can still be accepted
+        TransferNotExpired,
+        /// Asset not suspended
+        AssetNotSuspended,
+        /// Asset not rejected
+        AssetNotRejected,
+        /// Reason too long
+        ReasonTooLong,
     }
 
     // Dispatchable functions allow users to interact with the pallet and invoke state changes.
@@ -299,7 +568,10 @@ pub mod pallet {
             let asset_id = Self::next_asset_id();
             let current_block = <frame_system::Pallet<T>>::block_number();
 
-            let asset_info = AssetInfo {
+            let deposit = Self::registration_deposit(&name, &description, &metadata);
+            T::Currency::hold(&HoldReason::AssetRegistration.into(), &who, deposit)?;
+
+            let mut asset_info = AssetInfo {
                 owner: who.clone(),
                 name: name.clone(),
                 symbol: symbol.clone(),
@@ -312,9 +584,17 @@ pub mod pallet {
                 is_transferable,
                 is_mintable,
                 is_burnable,
+                deposit,
             };
 
-            Assets::<T>::insert(&asset_id, &asset_info);
+            // The history starts with the registration, from and to `Pending`
+            Self::set_status(
+                asset_id,
+                &mut asset_info,
+                AssetStatus::Pending,
+                Moderator::Signed(who.clone()),
+                Default::default(),
+            );
             AssetsByOwner::<T>::insert(&who, &asset_id, ());
             AssetCountByOwner::<T>::insert(&who, current_count.saturating_add(1));
             AssetBySymbol::<T>::insert(&symbol, &asset_id);
@@ -330,30 +610,42 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Approve an asset (requires root or governance)
+        /// Approve an asset (only by `ApproveOrigin`)
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::approve_asset())]
         pub fn approve_asset(
             origin: OriginFor<T>,
             asset_id: u32,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            let moderator = Self::moderator(&origin);
+            T::ApproveOrigin::ensure_origin(origin)?;
 
             let mut asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.status == AssetStatus::Pending, Error::<T>::AssetNotPending);
+            ensure!(
+                moderator != Moderator::Signed(asset.owner.clone()),
+                Error::<T>::CannotModerateOwnAsset
+            );
 
-            asset.status = AssetStatus::Approved;
-            Assets::<T>::insert(&asset_id, &asset);
+            Self::refund_deposit(asset_id, &mut asset)?;
+            Self::set_status(
+                asset_id,
+                &mut asset,
+                AssetStatus::Approved,
+                moderator.clone(),
+                Default::default(),
+            );
+            Self::set_balance(asset_id, &asset.owner, asset.total_supply);
 
             Self::deposit_event(Event::AssetApproved {
                 asset_id,
-                approver: who,
+                approver: moderator,
             });
 
             Ok(())
         }
 
-        /// Reject an asset (requires root or governance)
+        /// Reject an asset (only by `RejectOrigin`)
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::reject_asset())]
         pub fn reject_asset(
@@ -361,24 +653,36 @@ pub mod pallet {
             asset_id: u32,
             reason: Vec<u8>,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            let moderator = Self::moderator(&origin);
+            T::RejectOrigin::ensure_origin(origin)?;
 
             let mut asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.status == AssetStatus::Pending, Error::<T>::AssetNotPending);
+            ensure!(
+                moderator != Moderator::Signed(asset.owner.clone()),
+                Error::<T>::CannotModerateOwnAsset
+            );
+            let bounded_reason = Self::bounded_reason(&reason)?;
 
-            asset.status = AssetStatus::Rejected;
-            Assets::<T>::insert(&asset_id, &asset);
+            Self::slash_deposit(asset_id, &mut asset)?;
+            Self::set_status(
+                asset_id,
+                &mut asset,
+                AssetStatus::Rejected,
+                moderator.clone(),
+                bounded_reason,
+            );
 
             Self::deposit_event(Event::AssetRejected {
                 asset_id,
-                rejector: who,
+                rejector: moderator,
                 reason,
             });
 
             Ok(())
         }
 
-        /// Suspend an asset (requires root or governance)
+        /// Suspend an asset (only by `SuspendOrigin`)
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::suspend_asset())]
         pub fn suspend_asset(
@@ -386,23 +690,104 @@ pub mod pallet {
             asset_id: u32,
             reason: Vec<u8>,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            let moderator = Self::moderator(&origin);
+            T::SuspendOrigin::ensure_origin(origin)?;
 
             let mut asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.status == AssetStatus::Approved, Error::<T>::AssetNotApproved);
+            ensure!(
+                moderator != Moderator::Signed(asset.owner.clone()),
+                Error::<T>::CannotModerateOwnAsset
+            );
+            let bounded_reason = Self::bounded_reason(&reason)?;
 
-            asset.status = AssetStatus::Suspended;
-            Assets::<T>::insert(&asset_id, &asset);
+            Self::set_status(
+                asset_id,
+                &mut asset,
+                AssetStatus::Suspended,
+                moderator.clone(),
+                bounded_reason,
+            );
 
             Self::deposit_event(Event::AssetSuspended {
                 asset_id,
-                suspender: who,
+                suspender: moderator,
+                reason,
+            });
+
+            Ok(())
+        }
+
+        /// Reinstate a suspended asset (only by `SuspendOrigin`)
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::reinstate_asset())]
+        pub fn reinstate_asset(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            let moderator = Self::moderator(&origin);
+            T::SuspendOrigin::ensure_origin(origin)?;
+
+            let mut asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.status == AssetStatus::Suspended, Error::<T>::AssetNotSuspended);
+            ensure!(
+                moderator != Moderator::Signed(asset.owner.clone()),
+                Error::<T>::CannotModerateOwnAsset
+            );
+            let bounded_reason = Self::bounded_reason(&reason)?;
+
+            Self::set_status(
+                asset_id,
+                &mut asset,
+                AssetStatus::Approved,
+                moderator.clone(),
+                bounded_reason,
+            );
+
+            Self::deposit_event(Event::AssetReinstated {
+                asset_id,
+                reinstater: moderator,
                 reason,
             });
 
             Ok(())
         }
 
+        /// Resubmit a rejected asset for approval (only by owner)
+        ///
+        /// Holds the registration deposit again.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::resubmit_asset())]
+        pub fn resubmit_asset(
+            origin: OriginFor<T>,
+            asset_id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotAssetOwner);
+            ensure!(asset.status == AssetStatus::Rejected, Error::<T>::AssetNotRejected);
+
+            let deposit =
+                Self::registration_deposit(&asset.name, &asset.description, &asset.metadata);
+            Self::update_deposit(&mut asset, deposit)?;
+            Self::set_status(
+                asset_id,
+                &mut asset,
+                AssetStatus::Pending,
+                Moderator::Signed(who.clone()),
+                Default::default(),
+            );
+
+            Self::deposit_event(Event::AssetResubmitted {
+                asset_id,
+                owner: who,
+            });
+
+            Ok(())
+        }
+
         /// Update asset metadata (only by owner)
         #[pallet::call_index(4)]
         #[pallet::weight(T::WeightInfo::update_asset_metadata())]
@@ -420,6 +805,11 @@ pub mod pallet {
                 Error::<T>::AssetMetadataTooLong
             );
 
+            if asset.status == AssetStatus::Pending {
+                let deposit =
+                    Self::registration_deposit(&asset.name, &asset.description, &metadata);
+                Self::update_deposit(&mut asset, deposit)?;
+            }
             asset.metadata = metadata;
             Assets::<T>::insert(&asset_id, &asset);
 
@@ -431,18 +821,57 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Transfer asset ownership
-        #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::transfer_ownership())]
-        pub fn transfer_ownership(
+        /// Propose to transfer an asset to `new_owner`, who has to accept it
+        ///
+        /// Replaces any transfer already proposed for the asset. The proposal expires after
+        /// `OwnershipTransferPeriod` blocks.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::propose_ownership_transfer())]
+        pub fn propose_ownership_transfer(
             origin: OriginFor<T>,
             asset_id: u32,
             new_owner: T::AccountId,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            let mut asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            let asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.owner == who, Error::<T>::NotAssetOwner);
+            ensure!(new_owner != who, Error::<T>::AlreadyAssetOwner);
+
+            let expires_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::OwnershipTransferPeriod::get());
+            PendingOwnershipTransfers::<T>::insert(
+                &asset_id,
+                PendingTransfer { new_owner: new_owner.clone(), expires_at },
+            );
+
+            Self::deposit_event(Event::OwnershipTransferProposed {
+                asset_id,
+                from: who,
+                to: new_owner,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Accept the ownership of an asset proposed to the caller
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::accept_ownership())]
+        pub fn accept_ownership(
+            origin: OriginFor<T>,
+            asset_id: u32,
+        ) -> DispatchResult {
+            let new_owner = ensure_signed(origin)?;
+
+            let transfer = Self::pending_ownership_transfer(&asset_id)
+                .ok_or(Error::<T>::NoPendingTransfer)?;
+            ensure!(transfer.new_owner == new_owner, Error::<T>::NotPendingOwner);
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() <= transfer.expires_at,
+                Error::<T>::TransferExpired
+            );
+            let mut asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
 
             // Check new owner's asset count limit
             let new_owner_count = Self::asset_count_by_owner(&new_owner);
@@ -451,10 +880,18 @@ pub mod pallet {
                 Error::<T>::TooManyAssetsPerOwner
             );
 
-            // Update ownership
+            // The deposit of a pending asset moves to the new owner
             let old_owner = asset.owner.clone();
+            if asset.status == AssetStatus::Pending {
+                let reason: T::RuntimeHoldReason = HoldReason::AssetRegistration.into();
+                T::Currency::hold(&reason, &new_owner, asset.deposit)?;
+                T::Currency::release(&reason, &old_owner, asset.deposit, Precision::BestEffort)?;
+            }
+
+            // Update ownership
             asset.owner = new_owner.clone();
             Assets::<T>::insert(&asset_id, &asset);
+            PendingOwnershipTransfers::<T>::remove(&asset_id);
 
             // Update ownership mappings
             AssetsByOwner::<T>::remove(&old_owner, &asset_id);
@@ -473,11 +910,207 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Cancel the ownership transfer proposed for an asset (only by owner)
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::cancel_ownership_transfer())]
+        pub fn cancel_ownership_transfer(
+            origin: OriginFor<T>,
+            asset_id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotAssetOwner);
+            let transfer = PendingOwnershipTransfers::<T>::take(&asset_id)
+                .ok_or(Error::<T>::NoPendingTransfer)?;
+
+            Self::deposit_event(Event::OwnershipTransferCancelled {
+                asset_id,
+                to: transfer.new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Remove an ownership transfer that can no longer be accepted
+        ///
+        /// Can be called by anyone once the transfer has expired.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::remove_expired_ownership_transfer())]
+        pub fn remove_expired_ownership_transfer(
+            origin: OriginFor<T>,
+            asset_id: u32,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            let transfer = Self::pending_ownership_transfer(&asset_id)
+                .ok_or(Error::<T>::NoPendingTransfer)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() > transfer.expires_at,
+                Error::<T>::TransferNotExpired
+            );
+            PendingOwnershipTransfers::<T>::remove(&asset_id);
+
+            Self::deposit_event(Event::OwnershipTransferExpired {
+                asset_id,
+                to: transfer.new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Mint units of a mintable asset to `beneficiary` (only by owner)
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::mint())]
+        pub fn mint(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            beneficiary: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut asset = Self::approved_asset(asset_id)?;
+            ensure!(asset.owner == who, Error::<T>::NotAssetOwner);
+            ensure!(asset.is_mintable, Error::<T>::AssetNotMintable);
+            asset.total_supply =
+                asset.total_supply.checked_add(amount).ok_or(Error::<T>::SupplyOverflow)?;
+
+            Assets::<T>::insert(&asset_id, &asset);
+            Self::set_balance(
+                asset_id,
+                &beneficiary,
+                Self::balance_of(asset_id, &beneficiary).saturating_add(amount),
+            );
+
+            Self::deposit_event(Event::Minted {
+                asset_id,
+                to: beneficiary,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Burn units of a burnable asset from the caller's balance
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::burn())]
+        pub fn burn(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            amount: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut asset = Self::approved_asset(asset_id)?;
+            ensure!(asset.is_burnable, Error::<T>::AssetNotBurnable);
+            let balance = Self::balance_of(asset_id, &who);
+            ensure!(balance >= amount, Error::<T>::InsufficientBalance);
+
+            asset.total_supply = asset.total_supply.saturating_sub(amount);
+            Assets::<T>::insert(&asset_id, &asset);
+            Self::set_balance(asset_id, &who, balance - amount);
+
+            Self::deposit_event(Event::Burned {
+                asset_id,
+                from: who,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Transfer units of a transferable asset
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            dest: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let asset = Self::approved_asset(asset_id)?;
+            ensure!(asset.is_transferable, Error::<T>::AssetNotTransferable);
+
+            Self::do_transfer(asset_id, &who, &dest, amount)
+        }
+
+        /// Allow `spender` to transfer up to `amount` of the caller's units with `transfer_from`
+        ///
+        /// Replaces any previous allowance of the spender, zero revokes it.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::approve())]
+        pub fn approve(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            spender: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::approved_asset(asset_id)?;
+
+            if amount == 0 {
+                Allowances::<T>::remove((asset_id, &who, &spender));
+            } else {
+                Allowances::<T>::insert((asset_id, &who, &spender), amount);
+            }
+
+            Self::deposit_event(Event::TransferApproved {
+                asset_id,
+                owner: who,
+                spender,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Transfer units of a transferable asset on behalf of `owner`, within the allowance
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::transfer_from())]
+        pub fn transfer_from(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            owner: T::AccountId,
+            dest: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let asset = Self::approved_asset(asset_id)?;
+            ensure!(asset.is_transferable, Error::<T>::AssetNotTransferable);
+            let allowance = Self::allowance((asset_id, &owner, &who));
+            ensure!(allowance >= amount, Error::<T>::AllowanceExceeded);
+
+            Self::do_transfer(asset_id, &owner, &dest, amount)?;
+            if allowance == amount {
+                Allowances::<T>::remove((asset_id, &owner, &who));
+            } else {
+                Allowances::<T>::insert((asset_id, &owner, &who), allowance - amount);
+            }
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Kind of a moderation origin, recorded in the moderation events
+        fn moderator(origin: &OriginFor<T>) -> Moderator<T::AccountId> {
+            let origin: Result<frame_system::RawOrigin<T::AccountId>, OriginFor<T>> =
+                origin.clone().into();
+            match origin {
+                Ok(frame_system::RawOrigin::Root) => Moderator::Root,
+                Ok(frame_system::RawOrigin::Signed(who)) => Moderator::Signed(who),
+                _ => Moderator::Other,
+            }
+        }
+
         /// Get asset details
-        pub fn get_asset(asset_id: u32) -> Option<AssetInfo<T::AccountId, BlockNumberFor<T>>> {
+        pub fn get_asset(asset_id: u32) -> Option<AssetInfoOf<T>> {
             Self::assets(&asset_id)
         }
 
@@ -507,6 +1140,167 @@ pub mod pallet {
             }
         }
 
+        /// Balance `who` holds of an approved asset
+        pub fn approved_balance(asset_id: u32, who: &T::AccountId) -> u128 {
+            if Self::is_asset_approved(asset_id) {
+                Self::balance_of(asset_id, who)
+            } else {
+                0
+            }
+        }
+
+        /// Reason bounded to `MaxReasonLength`
+        fn bounded_reason(reason: &[u8]) -> Result<ReasonOf<T>, DispatchError> {
+            ReasonOf::<T>::try_from(reason.to_vec()).map_err(|_| Error::<T>::ReasonTooLong.into())
+        }
+
+        /// Store the asset with a new status and record the change in its status history
+        fn set_status(
+            asset_id: u32,
+            asset: &mut AssetInfoOf<T>,
+            status: AssetStatus,
+            actor: Moderator<T::AccountId>,
+            reason: ReasonOf<T>,
+        ) {
+            let change = StatusChange {
+                block: <frame_system::Pallet<T>>::block_number(),
+                from: sp_std::mem::replace(&mut asset.status, status.clone()),
+                to: status,
+                actor,
+                reason,
+            };
+            Assets::<T>::insert(&asset_id, &*asset);
+            StatusHistory::<T>::mutate(asset_id, |history| {
+                if history.is_full() && !history.is_empty() {
+                    history.remove(0);
+                }
+                let _ = history.try_push(change);
+            });
+        }
+
+        /// Deposit held for a pending asset with the given name, description and metadata
+        pub fn registration_deposit(
+            name: &[u8],
+            description: &[u8],
+            metadata: &[u8],
+        ) -> BalanceOf<T> {
+            let bytes = name.len().saturating_add(description.len()).saturating_add(metadata.len());
+            T::RegistrationDepositPerByte::get()
+                .saturating_mul((bytes as u32).into())
+                .saturating_add(T::RegistrationDeposit::get())
+        }
+
+        /// Hold more or release part of the deposit of a pending asset to match `deposit`
+        fn update_deposit(asset: &mut AssetInfoOf<T>, deposit: BalanceOf<T>) -> DispatchResult {
+            let reason: T::RuntimeHoldReason = HoldReason::AssetRegistration.into();
+            if deposit > asset.deposit {
+                T::Currency::hold(&reason, &asset.owner, deposit - asset.deposit)?;
+            } else if deposit < asset.deposit {
+                T::Currency::release(
+                    &reason,
+                    &asset.owner,
+                    asset.deposit - deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+            asset.deposit = deposit;
+            Ok(())
+        }
+
+        /// Release the whole deposit of an approved asset to its owner
+        fn refund_deposit(asset_id: u32, asset: &mut AssetInfoOf<T>) -> DispatchResult {
+            let amount = asset.deposit;
+            asset.deposit = Zero::zero();
+            if amount.is_zero() {
+                return Ok(())
+            }
+            T::Currency::release(
+                &HoldReason::AssetRegistration.into(),
+                &asset.owner,
+                amount,
+                Precision::BestEffort,
+            )?;
+            Self::deposit_event(Event::DepositRefunded {
+                asset_id,
+                who: asset.owner.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Burn `RejectionBurn` of the deposit of a rejected asset and refund the rest
+        fn slash_deposit(asset_id: u32, asset: &mut AssetInfoOf<T>) -> DispatchResult {
+            let burned = T::RejectionBurn::get().mul_floor(asset.deposit);
+            if !burned.is_zero() {
+                T::Currency::burn_held(
+                    &HoldReason::AssetRegistration.into(),
+                    &asset.owner,
+                    burned,
+                    Precision::BestEffort,
+                    Fortitude::Force,
+                )?;
+                Self::deposit_event(Event::DepositSlashed {
+                    asset_id,
+                    who: asset.owner.clone(),
+                    amount: burned,
+                });
+            }
+            asset.deposit = asset.deposit.saturating_sub(burned);
+            Self::refund_deposit(asset_id, asset)
+        }
+
+        /// Approved asset, or an error if it does not exist or is not approved
+        pub(crate) fn approved_asset(
+            asset_id: u32,
+        ) -> Result<AssetInfoOf<T>, DispatchError> {
+            let asset = Self::assets(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.status == AssetStatus::Approved, Error::<T>::AssetNotApproved);
+            Ok(asset)
+        }
+
+        /// Set the balance of `who`, notifying `OnBalanceChange` first
+        pub(crate) fn set_balance(asset_id: u32, who: &T::AccountId, amount: u128) {
+            let previous = Self::balance_of(asset_id, who);
+            if previous == amount {
+                return
+            }
+            T::OnBalanceChange::on_balance_change(asset_id, who, previous);
+            if amount == 0 {
+                AssetBalances::<T>::remove(asset_id, who);
+            } else {
+                AssetBalances::<T>::insert(asset_id, who, amount);
+            }
+        }
+
+        /// Move `amount` of an asset between two accounts
+        pub(crate) fn do_transfer(
+            asset_id: u32,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let balance = Self::balance_of(asset_id, from);
+            ensure!(balance >= amount, Error::<T>::InsufficientBalance);
+
+            if from != to {
+                Self::set_balance(asset_id, from, balance - amount);
+                Self::set_balance(
+                    asset_id,
+                    to,
+                    Self::balance_of(asset_id, to).saturating_add(amount),
+                );
+            }
+
+            Self::deposit_event(Event::Transferred {
+                asset_id,
+                from: from.clone(),
+                to: to.clone(),
+                amount,
+            });
+
+            Ok(())
+        }
+
         /// Get total number of assets
         pub fn total_assets() -> u32 {
             Self::next_asset_id()
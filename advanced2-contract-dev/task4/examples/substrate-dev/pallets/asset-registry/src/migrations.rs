@@ -0,0 +1,204 @@
+//! Storage migrations for the asset registry pallet.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    storage_alias,
+    traits::{fungible::MutateHold, OnRuntimeUpgrade},
+};
+use sp_runtime::traits::Zero;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Log target of the asset registry migrations
+const LOG_TARGET: &str = "runtime::asset-registry";
+
+/// Migrate approved assets to the balance ledger.
+pub mod v1 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// Asset information before version 2, without the registration deposit
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    pub struct OldAssetInfo<AccountId, BlockNumber> {
+        pub owner: AccountId,
+        pub name: Vec<u8>,
+        pub symbol: Vec<u8>,
+        pub description: Vec<u8>,
+        pub decimals: u8,
+        pub total_supply: u128,
+        pub status: AssetStatus,
+        pub registered_at: BlockNumber,
+        pub metadata: Vec<u8>,
+        pub is_transferable: bool,
+        pub is_mintable: bool,
+        pub is_burnable: bool,
+    }
+
+    pub type OldAssetInfoOf<T> =
+        OldAssetInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    #[storage_alias]
+    pub type Assets<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, u32, OldAssetInfoOf<T>, OptionQuery>;
+
+    /// Credits the owner of every approved or suspended asset with its total supply.
+    ///
+    /// Before the ledger existed the owner was considered to hold the whole supply.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut reads = 1u64;
+            let mut credited = 0u64;
+            for (asset_id, asset) in Assets::<T>::iter() {
+                reads += 1;
+                if matches!(asset.status, AssetStatus::Approved | AssetStatus::Suspended) {
+                    credited += 1;
+                    AssetBalances::<T>::insert(asset_id, &asset.owner, asset.total_supply);
+                }
+            }
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(reads, credited.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let credited = v1::Assets::<T>::iter_values()
+                .filter(|asset| {
+                    matches!(asset.status, AssetStatus::Approved | AssetStatus::Suspended)
+                })
+                .count() as u32;
+            Ok(credited.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let credited = u32::decode(&mut &state[..])
+                .map_err(|_| "the state parameter should be the number of credited assets")?;
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "storage version should be 1 after the migration"
+            );
+            ensure!(
+                AssetBalances::<T>::iter().count() as u32 == credited,
+                "every approved asset owner should be credited after the migration"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Migrate assets to record the registration deposit.
+pub mod v2 {
+    use super::*;
+
+    /// Holds the registration deposit of every pending asset.
+    ///
+    /// Moderated assets keep a zero deposit, nothing is refunded or slashed for them. A pending
+    /// asset whose owner cannot afford the deposit stays pending with a zero deposit and the
+    /// shortfall is logged.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut translated = 0u64;
+            let mut pending = Vec::new();
+            Assets::<T>::translate::<v1::OldAssetInfoOf<T>, _>(|asset_id, old| {
+                if old.status == AssetStatus::Pending {
+                    pending.push(asset_id);
+                }
+                translated += 1;
+                Some(AssetInfo {
+                    owner: old.owner,
+                    name: old.name,
+                    symbol: old.symbol,
+                    description: old.description,
+                    decimals: old.decimals,
+                    total_supply: old.total_supply,
+                    status: old.status,
+                    registered_at: old.registered_at,
+                    metadata: old.metadata,
+                    is_transferable: old.is_transferable,
+                    is_mintable: old.is_mintable,
+                    is_burnable: old.is_burnable,
+                    deposit: Zero::zero(),
+                })
+            });
+
+            let reason: T::RuntimeHoldReason = HoldReason::AssetRegistration.into();
+            for asset_id in pending.iter() {
+                let Some(mut asset) = Assets::<T>::get(asset_id) else { continue };
+                let deposit = Pallet::<T>::registration_deposit(
+                    &asset.name,
+                    &asset.description,
+                    &asset.metadata,
+                );
+                if T::Currency::hold(&reason, &asset.owner, deposit).is_ok() {
+                    asset.deposit = deposit;
+                    Assets::<T>::insert(asset_id, asset);
+                } else {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "asset {} stays pending without a deposit, its owner cannot hold {:?}",
+                        asset_id,
+                        deposit,
+                    );
+                }
+            }
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            // Holding a deposit reads and writes the asset, the owner's holds and account
+            let held = (pending.len() as u64).saturating_mul(3);
+            T::DbWeight::get().reads_writes(
+                translated.saturating_add(held).saturating_add(1),
+                translated.saturating_add(held).saturating_add(1),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let assets = v1::Assets::<T>::iter().count() as u32;
+            Ok(assets.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let assets = u32::decode(&mut &state[..])
+                .map_err(|_| "the state parameter should be the number of assets")?;
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "storage version should be 2 after the migration"
+            );
+            ensure!(
+                Assets::<T>::iter().count() as u32 == assets,
+                "every asset should decode after the migration"
+            );
+            ensure!(
+                Assets::<T>::iter_values().all(|asset| {
+                    asset.status != AssetStatus::Pending ||
+                        asset.deposit.is_zero() ||
+                        asset.deposit ==
+                            Pallet::<T>::registration_deposit(
+                                &asset.name,
+                                &asset.description,
+                                &asset.metadata,
+                            )
+                }),
+                "every pending asset should hold its full deposit or none after the migration"
+            );
+            Ok(())
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use crate as pallet_asset_registry;
 use frame_support::{
-    parameter_types,
-    traits::{ConstU16, ConstU64, ConstU32},
+    ord_parameter_types, parameter_types,
+    traits::{ConstU16, ConstU64, ConstU32, EitherOfDiverse},
 };
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup}, BuildStorage,
+    traits::{BlakeTwo256, IdentityLookup}, BuildStorage, Perbill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -15,6 +16,7 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
         AssetRegistryModule: pallet_asset_registry,
     }
 );
@@ -36,7 +38,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -45,12 +47,35 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type MaxHolds = ConstU32<1>;
+}
+
 parameter_types! {
     pub const MaxAssetNameLength: u32 = 100;
     pub const MaxAssetSymbolLength: u32 = 20;
     pub const MaxAssetDescriptionLength: u32 = 1000;
     pub const MaxAssetMetadataLength: u32 = 2000;
     pub const MaxAssetsPerOwner: u32 = 100;
+    pub const RejectionBurn: Perbill = Perbill::from_percent(50);
+}
+
+pub const MODERATOR: u64 = 10;
+
+ord_parameter_types! {
+    pub const Moderators: u64 = MODERATOR;
 }
 
 impl pallet_asset_registry::Config for Test {
@@ -61,9 +86,27 @@ impl pallet_asset_registry::Config for Test {
     type MaxAssetDescriptionLength = MaxAssetDescriptionLength;
     type MaxAssetMetadataLength = MaxAssetMetadataLength;
     type MaxAssetsPerOwner = MaxAssetsPerOwner;
+    type OnBalanceChange = ();
+    type ApproveOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<Moderators, u64>>;
+    type RejectOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<Moderators, u64>>;
+    type SuspendOrigin = EnsureRoot<u64>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RegistrationDeposit = ConstU64<10>;
+    type RegistrationDepositPerByte = ConstU64<1>;
+    type RejectionBurn = RejectionBurn;
+    type OwnershipTransferPeriod = ConstU64<10>;
+    type MaxReasonLength = ConstU32<32>;
+    type MaxStatusHistory = ConstU32<4>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=10).map(|who| (who, 1000)).collect(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
 }
\ No newline at end of file
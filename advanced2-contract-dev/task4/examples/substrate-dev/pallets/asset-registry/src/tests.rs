@@ -1,5 +1,20 @@
-use crate::{mock::*, Error, Event, AssetStatus};
-use frame_support::{assert_noop, assert_ok, traits::Get};
+use crate::{
+    migrations::{
+        v1::{self, MigrateToV1},
+        v2::MigrateToV2,
+    },
+    mock::*,
+    AssetBalances, AssetStatus, Error, Event, Moderator, StatusChange,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        fungibles::{Inspect, Mutate},
+        tokens::{Fortitude, Precision, Preservation},
+        Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+    },
+};
+use sp_runtime::{DispatchError, TokenError};
 
 #[test]
 fn register_asset_works() {
@@ -206,7 +221,7 @@ fn approve_asset_works() {
 
         // Approve the asset
         assert_ok!(AssetRegistryModule::approve_asset(
-            RuntimeOrigin::signed(2), // approver
+            RuntimeOrigin::signed(MODERATOR), // approver
             0
         ));
 
@@ -217,7 +232,7 @@ fn approve_asset_works() {
         // Check that the event was emitted
         System::assert_last_event(Event::AssetApproved {
             asset_id: 0,
-            approver: 2,
+            approver: Moderator::Signed(MODERATOR),
         }.into());
     });
 }
@@ -226,7 +241,7 @@ fn approve_asset_works() {
 fn approve_asset_fails_with_nonexistent_asset() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            AssetRegistryModule::approve_asset(RuntimeOrigin::signed(1), 0),
+            AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0),
             Error::<Test>::AssetNotFound
         );
     });
@@ -255,7 +270,7 @@ fn reject_asset_works() {
 
         // Reject the asset
         assert_ok!(AssetRegistryModule::reject_asset(
-            RuntimeOrigin::signed(2), // rejector
+            RuntimeOrigin::signed(MODERATOR), // rejector
             0,
             reason.clone()
         ));
@@ -267,7 +282,7 @@ fn reject_asset_works() {
         // Check that the event was emitted
         System::assert_last_event(Event::AssetRejected {
             asset_id: 0,
-            rejector: 2,
+            rejector: Moderator::Signed(MODERATOR),
             reason,
         }.into());
     });
@@ -293,7 +308,7 @@ fn suspend_asset_works() {
         ));
 
         assert_ok!(AssetRegistryModule::approve_asset(
-            RuntimeOrigin::signed(2),
+            RuntimeOrigin::signed(MODERATOR),
             0
         ));
 
@@ -301,7 +316,7 @@ fn suspend_asset_works() {
 
         // Suspend the asset
         assert_ok!(AssetRegistryModule::suspend_asset(
-            RuntimeOrigin::signed(3), // suspender
+            RuntimeOrigin::root(), // suspender
             0,
             reason.clone()
         ));
@@ -313,7 +328,7 @@ fn suspend_asset_works() {
         // Check that the event was emitted
         System::assert_last_event(Event::AssetSuspended {
             asset_id: 0,
-            suspender: 3,
+            suspender: Moderator::Root,
             reason,
         }.into());
     });
@@ -409,12 +424,27 @@ fn transfer_ownership_works() {
             true,
         ));
 
-        // Transfer ownership
-        assert_ok!(AssetRegistryModule::transfer_ownership(
+        // Propose the transfer
+        assert_ok!(AssetRegistryModule::propose_ownership_transfer(
             RuntimeOrigin::signed(1), // current owner
             0,
             2 // new owner
         ));
+        System::assert_last_event(Event::OwnershipTransferProposed {
+            asset_id: 0,
+            from: 1,
+            to: 2,
+            expires_at: 11,
+        }.into());
+
+        // Nothing changes until the new owner accepts
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().owner, 1);
+        assert_noop!(
+            AssetRegistryModule::accept_ownership(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::NotPendingOwner
+        );
+        assert_ok!(AssetRegistryModule::accept_ownership(RuntimeOrigin::signed(2), 0));
+        assert!(AssetRegistryModule::pending_ownership_transfer(0).is_none());
 
         // Check that the ownership was transferred
         let asset = AssetRegistryModule::assets(0).unwrap();
@@ -458,12 +488,849 @@ fn transfer_ownership_fails_when_not_owner() {
 
         // Try to transfer ownership as non-owner
         assert_noop!(
-            AssetRegistryModule::transfer_ownership(
+            AssetRegistryModule::propose_ownership_transfer(
                 RuntimeOrigin::signed(2), // not owner
                 0,
                 3
             ),
             Error::<Test>::NotAssetOwner
         );
+        assert_noop!(
+            AssetRegistryModule::propose_ownership_transfer(RuntimeOrigin::signed(1), 0, 1),
+            Error::<Test>::AlreadyAssetOwner
+        );
+        assert_noop!(
+            AssetRegistryModule::accept_ownership(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::NoPendingTransfer
+        );
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn ownership_transfers_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        assert_ok!(AssetRegistryModule::propose_ownership_transfer(RuntimeOrigin::signed(1), 0, 2));
+
+        System::set_block_number(12);
+        assert_noop!(
+            AssetRegistryModule::accept_ownership(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::TransferExpired
+        );
+
+        // Proposing again restarts the period
+        assert_ok!(AssetRegistryModule::propose_ownership_transfer(RuntimeOrigin::signed(1), 0, 2));
+        System::set_block_number(22);
+        assert_ok!(AssetRegistryModule::accept_ownership(RuntimeOrigin::signed(2), 0));
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().owner, 2);
+    });
+}
+
+#[test]
+fn expired_ownership_transfers_can_be_removed_by_anyone() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        assert_ok!(AssetRegistryModule::propose_ownership_transfer(RuntimeOrigin::signed(1), 0, 2));
+
+        System::set_block_number(11);
+        assert_noop!(
+            AssetRegistryModule::remove_expired_ownership_transfer(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::TransferNotExpired
+        );
+
+        System::set_block_number(12);
+        assert_ok!(AssetRegistryModule::remove_expired_ownership_transfer(
+            RuntimeOrigin::signed(3),
+            0
+        ));
+        assert!(AssetRegistryModule::pending_ownership_transfer(0).is_none());
+        System::assert_last_event(Event::OwnershipTransferExpired { asset_id: 0, to: 2 }.into());
+
+        assert_noop!(
+            AssetRegistryModule::remove_expired_ownership_transfer(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+    });
+}
+
+#[test]
+fn ownership_transfers_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        assert_ok!(AssetRegistryModule::propose_ownership_transfer(RuntimeOrigin::signed(1), 0, 2));
+
+        assert_noop!(
+            AssetRegistryModule::cancel_ownership_transfer(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_ok!(AssetRegistryModule::cancel_ownership_transfer(RuntimeOrigin::signed(1), 0));
+        System::assert_last_event(Event::OwnershipTransferCancelled { asset_id: 0, to: 2 }.into());
+
+        assert_noop!(
+            AssetRegistryModule::accept_ownership(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+        assert_noop!(
+            AssetRegistryModule::cancel_ownership_transfer(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+    });
+}
+
+#[test]
+fn moderation_requires_configured_origins() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+
+        assert_noop!(
+            AssetRegistryModule::approve_asset(RuntimeOrigin::signed(2), 0),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AssetRegistryModule::reject_asset(RuntimeOrigin::signed(2), 0, b"Spam".to_vec()),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0));
+        System::assert_last_event(Event::AssetApproved {
+            asset_id: 0,
+            approver: Moderator::Root,
+        }.into());
+
+        // Suspensions are reserved to root
+        assert_noop!(
+            AssetRegistryModule::suspend_asset(
+                RuntimeOrigin::signed(MODERATOR),
+                0,
+                b"Spam".to_vec()
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn moderator_cannot_approve_own_asset() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(MODERATOR),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+
+        assert_noop!(
+            AssetRegistryModule::approve_asset(RuntimeOrigin::signed(MODERATOR), 0),
+            Error::<Test>::CannotModerateOwnAsset
+        );
+    });
+}
+
+/// Registers an asset with a supply of 1000 owned by `owner` and approves it
+fn approved_token(owner: u64, transferable: bool, mintable: bool, burnable: bool) {
+    assert_ok!(AssetRegistryModule::register_asset(
+        RuntimeOrigin::signed(owner),
+        b"Test Token".to_vec(),
+        b"TEST".to_vec(),
+        b"Description".to_vec(),
+        18,
+        1000,
+        b"{}".to_vec(),
+        transferable,
+        mintable,
+        burnable,
+    ));
+    assert_ok!(AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0));
+}
+
+#[test]
+fn approval_credits_the_owner_with_the_supply() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 0);
+        assert_noop!(
+            AssetRegistryModule::transfer(RuntimeOrigin::signed(1), 0, 2, 10),
+            Error::<Test>::AssetNotApproved
+        );
+
+        assert_ok!(AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0));
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 1000);
+        assert_eq!(AssetRegistryModule::approved_balance(0, &1), 1000);
+    });
+}
+
+#[test]
+fn mint_and_burn_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        approved_token(1, true, true, true);
+
+        assert_noop!(
+            AssetRegistryModule::mint(RuntimeOrigin::signed(2), 0, 2, 500),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_ok!(AssetRegistryModule::mint(RuntimeOrigin::signed(1), 0, 2, 500));
+        System::assert_last_event(Event::Minted { asset_id: 0, to: 2, amount: 500 }.into());
+        assert_eq!(AssetRegistryModule::balance_of(0, 2), 500);
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().total_supply, 1500);
+        assert_noop!(
+            AssetRegistryModule::mint(RuntimeOrigin::signed(1), 0, 2, u128::MAX),
+            Error::<Test>::SupplyOverflow
+        );
+
+        assert_noop!(
+            AssetRegistryModule::burn(RuntimeOrigin::signed(2), 0, 501),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_ok!(AssetRegistryModule::burn(RuntimeOrigin::signed(2), 0, 500));
+        System::assert_last_event(Event::Burned { asset_id: 0, from: 2, amount: 500 }.into());
+        assert_eq!(AssetRegistryModule::balance_of(0, 2), 0);
+        assert!(!AssetBalances::<Test>::contains_key(0, 2));
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().total_supply, 1000);
+    });
+}
+
+#[test]
+fn transfer_and_allowances_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        approved_token(1, true, true, true);
+
+        assert_ok!(AssetRegistryModule::transfer(RuntimeOrigin::signed(1), 0, 2, 300));
+        System::assert_last_event(
+            Event::Transferred { asset_id: 0, from: 1, to: 2, amount: 300 }.into(),
+        );
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 700);
+        assert_eq!(AssetRegistryModule::balance_of(0, 2), 300);
+        assert_noop!(
+            AssetRegistryModule::transfer(RuntimeOrigin::signed(2), 0, 3, 301),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(AssetRegistryModule::approve(RuntimeOrigin::signed(2), 0, 3, 200));
+        System::assert_last_event(
+            Event::TransferApproved { asset_id: 0, owner: 2, spender: 3, amount: 200 }.into(),
+        );
+        assert_noop!(
+            AssetRegistryModule::transfer_from(RuntimeOrigin::signed(3), 0, 2, 4, 201),
+            Error::<Test>::AllowanceExceeded
+        );
+        assert_ok!(AssetRegistryModule::transfer_from(RuntimeOrigin::signed(3), 0, 2, 4, 150));
+        assert_eq!(AssetRegistryModule::balance_of(0, 2), 150);
+        assert_eq!(AssetRegistryModule::balance_of(0, 4), 150);
+        assert_eq!(AssetRegistryModule::allowance((0, 2, 3)), 50);
+
+        assert_ok!(AssetRegistryModule::transfer_from(RuntimeOrigin::signed(3), 0, 2, 3, 50));
+        assert_eq!(AssetRegistryModule::allowance((0, 2, 3)), 0);
+        assert_eq!(AssetRegistryModule::balance_of(0, 3), 50);
+
+        // Supply is unchanged by transfers
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().total_supply, 1000);
+    });
+}
+
+#[test]
+fn asset_flags_are_enforced() {
+    new_test_ext().execute_with(|| {
+        approved_token(1, false, false, false);
+        assert_ok!(AssetRegistryModule::approve(RuntimeOrigin::signed(1), 0, 2, 100));
+
+        assert_noop!(
+            AssetRegistryModule::transfer(RuntimeOrigin::signed(1), 0, 2, 10),
+            Error::<Test>::AssetNotTransferable
+        );
+        assert_noop!(
+            AssetRegistryModule::transfer_from(RuntimeOrigin::signed(2), 0, 1, 2, 10),
+            Error::<Test>::AssetNotTransferable
+        );
+        assert_noop!(
+            AssetRegistryModule::mint(RuntimeOrigin::signed(1), 0, 1, 10),
+            Error::<Test>::AssetNotMintable
+        );
+        assert_noop!(
+            AssetRegistryModule::burn(RuntimeOrigin::signed(1), 0, 10),
+            Error::<Test>::AssetNotBurnable
+        );
+    });
+}
+
+#[test]
+fn suspended_assets_are_frozen() {
+    new_test_ext().execute_with(|| {
+        approved_token(1, true, true, true);
+        assert_ok!(AssetRegistryModule::suspend_asset(RuntimeOrigin::root(), 0, b"Spam".to_vec()));
+
+        assert_noop!(
+            AssetRegistryModule::transfer(RuntimeOrigin::signed(1), 0, 2, 10),
+            Error::<Test>::AssetNotApproved
+        );
+        assert_noop!(
+            AssetRegistryModule::burn(RuntimeOrigin::signed(1), 0, 10),
+            Error::<Test>::AssetNotApproved
+        );
+        // Balances are kept, but no longer count
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 1000);
+        assert_eq!(AssetRegistryModule::approved_balance(0, &1), 0);
+    });
+}
+
+#[test]
+fn fungibles_traits_use_the_ledger() {
+    new_test_ext().execute_with(|| {
+        approved_token(1, true, true, true);
+
+        assert!(<AssetRegistryModule as Inspect<u64>>::asset_exists(0));
+        assert_eq!(<AssetRegistryModule as Inspect<u64>>::total_issuance(0), 1000);
+        assert_eq!(<AssetRegistryModule as Inspect<u64>>::balance(0, &1), 1000);
+
+        assert_ok!(<AssetRegistryModule as Mutate<u64>>::mint_into(0, &2, 100));
+        assert_eq!(AssetRegistryModule::balance_of(0, 2), 100);
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().total_supply, 1100);
+
+        assert_ok!(<AssetRegistryModule as Mutate<u64>>::transfer(
+            0,
+            &1,
+            &2,
+            400,
+            Preservation::Expendable
+        ));
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 600);
+        assert_eq!(AssetRegistryModule::balance_of(0, 2), 500);
+    });
+}
+
+#[test]
+fn fungibles_traits_respect_asset_status_and_flags() {
+    new_test_ext().execute_with(|| {
+        approved_token(1, true, false, true);
+        assert_noop!(
+            <AssetRegistryModule as Mutate<u64>>::mint_into(0, &2, 100),
+            TokenError::Blocked
+        );
+
+        assert_ok!(AssetRegistryModule::suspend_asset(RuntimeOrigin::root(), 0, b"Spam".to_vec()));
+        assert!(<AssetRegistryModule as Mutate<u64>>::transfer(
+            0,
+            &1,
+            &2,
+            100,
+            Preservation::Expendable
+        )
+        .is_err());
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 1000);
+    });
+}
+
+#[test]
+fn fungibles_burn_respects_burnable() {
+    new_test_ext().execute_with(|| {
+        approved_token(1, true, true, false);
+        assert_noop!(
+            <AssetRegistryModule as Mutate<u64>>::burn_from(
+                0,
+                &1,
+                100,
+                Precision::Exact,
+                Fortitude::Force
+            ),
+            TokenError::Blocked
+        );
+        assert_noop!(<AssetRegistryModule as Mutate<u64>>::shelve(0, &1, 100), TokenError::Blocked);
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().total_supply, 1000);
+    });
+}
+
+#[test]
+fn fungibles_non_transferable_assets_can_only_be_burned() {
+    new_test_ext().execute_with(|| {
+        approved_token(1, false, true, true);
+        assert_eq!(
+            <AssetRegistryModule as Inspect<u64>>::reducible_balance(
+                0,
+                &1,
+                Preservation::Expendable,
+                Fortitude::Polite
+            ),
+            0
+        );
+        assert!(<AssetRegistryModule as Mutate<u64>>::transfer(
+            0,
+            &1,
+            &2,
+            100,
+            Preservation::Expendable
+        )
+        .is_err());
+
+        assert_ok!(<AssetRegistryModule as Mutate<u64>>::burn_from(
+            0,
+            &1,
+            100,
+            Precision::Exact,
+            Fortitude::Polite
+        ));
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 900);
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().total_supply, 900);
+    });
+}
+
+/// Asset as stored before version 2
+fn old_asset(owner: u64, symbol: &[u8], status: AssetStatus) -> v1::OldAssetInfoOf<Test> {
+    v1::OldAssetInfo {
+        owner,
+        name: b"Test Token".to_vec(),
+        symbol: symbol.to_vec(),
+        description: b"Description".to_vec(),
+        decimals: 18,
+        total_supply: 1000,
+        status,
+        registered_at: 0,
+        metadata: b"{}".to_vec(),
+        is_transferable: true,
+        is_mintable: true,
+        is_burnable: true,
+    }
+}
+
+#[test]
+fn migration_to_v1_credits_owners() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<AssetRegistryModule>();
+        v1::Assets::<Test>::insert(0, old_asset(1, b"TEST", AssetStatus::Approved));
+        v1::Assets::<Test>::insert(1, old_asset(2, b"PEND", AssetStatus::Pending));
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(AssetRegistryModule::on_chain_storage_version(), 1);
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 1000);
+        assert_eq!(AssetRegistryModule::balance_of(1, 2), 0);
+
+        // Running it again is a no-op
+        AssetBalances::<Test>::remove(0, 1);
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 0);
+    });
+}
+
+#[test]
+fn migration_to_v2_holds_deposits_of_pending_assets() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<AssetRegistryModule>();
+        v1::Assets::<Test>::insert(0, old_asset(1, b"TEST", AssetStatus::Pending));
+        v1::Assets::<Test>::insert(1, old_asset(2, b"APPR", AssetStatus::Approved));
+        // Account 11 has no funds to hold the deposit from
+        v1::Assets::<Test>::insert(2, old_asset(11, b"POOR", AssetStatus::Pending));
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(AssetRegistryModule::on_chain_storage_version(), 2);
+        let asset = AssetRegistryModule::assets(0).unwrap();
+        assert_eq!(asset.deposit, DEPOSIT);
+        assert_eq!(asset.status, AssetStatus::Pending);
+        assert_eq!(Balances::reserved_balance(1), DEPOSIT);
+
+        // Moderated assets hold nothing
+        assert_eq!(AssetRegistryModule::assets(1).unwrap().deposit, 0);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // An unaffordable deposit leaves the asset pending without a deposit
+        let asset = AssetRegistryModule::assets(2).unwrap();
+        assert_eq!(asset.deposit, 0);
+        assert_eq!(asset.status, AssetStatus::Pending);
+        assert!(AssetRegistryModule::status_history(2).is_empty());
+
+        // The held deposit is refunded on approval
+        assert_ok!(AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1000);
+    });
+}
+
+/// Deposit held for the `Test Token` assets registered by the tests
+const DEPOSIT: u64 = 10 + 10 + 11 + 2;
+
+#[test]
+fn registration_holds_a_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+
+        assert_eq!(
+            AssetRegistryModule::registration_deposit(b"Test Token", b"Description", b"{}"),
+            DEPOSIT
+        );
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().deposit, DEPOSIT);
+        assert_eq!(Balances::reserved_balance(1), DEPOSIT);
+        assert_eq!(Balances::free_balance(1), 1000 - DEPOSIT);
+
+        // Longer metadata holds more, shorter releases the difference
+        assert_ok!(AssetRegistryModule::update_asset_metadata(
+            RuntimeOrigin::signed(1),
+            0,
+            b"{\"a\":1}".to_vec()
+        ));
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().deposit, DEPOSIT + 5);
+        assert_eq!(Balances::reserved_balance(1), DEPOSIT + 5);
+        assert_ok!(AssetRegistryModule::update_asset_metadata(RuntimeOrigin::signed(1), 0, vec![]));
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().deposit, DEPOSIT - 2);
+        assert_eq!(Balances::reserved_balance(1), DEPOSIT - 2);
+    });
+}
+
+#[test]
+fn registration_fails_without_funds_for_the_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AssetRegistryModule::register_asset(
+                RuntimeOrigin::signed(11),
+                b"Test Token".to_vec(),
+                b"TEST".to_vec(),
+                b"Description".to_vec(),
+                18,
+                1000,
+                b"{}".to_vec(),
+                true,
+                true,
+                true,
+            ),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn approval_refunds_the_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        approved_token(1, true, true, true);
+
+        System::assert_has_event(
+            Event::DepositRefunded { asset_id: 0, who: 1, amount: DEPOSIT }.into(),
+        );
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().deposit, 0);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1000);
+    });
+}
+
+#[test]
+fn rejection_burns_part_of_the_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(AssetRegistryModule::reject_asset(RuntimeOrigin::root(), 0, b"Spam".to_vec()));
+
+        // Half is burned, the rest refunded
+        let burned = DEPOSIT / 2;
+        System::assert_has_event(Event::DepositSlashed { asset_id: 0, who: 1, amount: burned }.into());
+        System::assert_has_event(
+            Event::DepositRefunded { asset_id: 0, who: 1, amount: DEPOSIT - burned }.into(),
+        );
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().deposit, 0);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1000 - burned);
+        assert_eq!(Balances::total_issuance(), issuance - burned);
+    });
+}
+
+#[test]
+fn ownership_transfer_moves_the_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+
+        assert_ok!(AssetRegistryModule::propose_ownership_transfer(RuntimeOrigin::signed(1), 0, 2));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_ok!(AssetRegistryModule::accept_ownership(RuntimeOrigin::signed(2), 0));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), DEPOSIT);
+
+        // The new owner is refunded on approval
+        assert_ok!(AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 1000);
+    });
+}
+
+#[test]
+fn reinstate_asset_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        approved_token(1, true, true, true);
+
+        assert_noop!(
+            AssetRegistryModule::reinstate_asset(RuntimeOrigin::root(), 0, b"Resolved".to_vec()),
+            Error::<Test>::AssetNotSuspended
+        );
+        assert_ok!(AssetRegistryModule::suspend_asset(RuntimeOrigin::root(), 0, b"Spam".to_vec()));
+        assert_noop!(
+            AssetRegistryModule::reinstate_asset(
+                RuntimeOrigin::signed(MODERATOR),
+                0,
+                b"Resolved".to_vec()
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AssetRegistryModule::reinstate_asset(
+            RuntimeOrigin::root(),
+            0,
+            b"Resolved".to_vec()
+        ));
+        System::assert_last_event(Event::AssetReinstated {
+            asset_id: 0,
+            reinstater: Moderator::Root,
+            reason: b"Resolved".to_vec(),
+        }.into());
+
+        // Balances count again
+        assert_eq!(AssetRegistryModule::assets(0).unwrap().status, AssetStatus::Approved);
+        assert_eq!(AssetRegistryModule::approved_balance(0, &1), 1000);
+        assert_ok!(AssetRegistryModule::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+    });
+}
+
+#[test]
+fn resubmit_asset_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        assert_noop!(
+            AssetRegistryModule::resubmit_asset(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::AssetNotRejected
+        );
+        assert_ok!(AssetRegistryModule::reject_asset(RuntimeOrigin::root(), 0, b"Spam".to_vec()));
+
+        assert_noop!(
+            AssetRegistryModule::resubmit_asset(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_ok!(AssetRegistryModule::resubmit_asset(RuntimeOrigin::signed(1), 0));
+        System::assert_last_event(Event::AssetResubmitted { asset_id: 0, owner: 1 }.into());
+
+        // The deposit is held again
+        let asset = AssetRegistryModule::assets(0).unwrap();
+        assert_eq!(asset.status, AssetStatus::Pending);
+        assert_eq!(asset.deposit, DEPOSIT);
+        assert_eq!(Balances::reserved_balance(1), DEPOSIT);
+
+        assert_ok!(AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0));
+        assert_eq!(AssetRegistryModule::balance_of(0, 1), 1000);
+    });
+}
+
+#[test]
+fn status_history_records_every_change() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        assert_eq!(
+            AssetRegistryModule::status_history(0).into_inner(),
+            vec![StatusChange {
+                block: 1,
+                from: AssetStatus::Pending,
+                to: AssetStatus::Pending,
+                actor: Moderator::Signed(1),
+                reason: Default::default(),
+            }]
+        );
+
+        assert_ok!(AssetRegistryModule::reject_asset(
+            RuntimeOrigin::signed(MODERATOR),
+            0,
+            b"Spam".to_vec()
+        ));
+        System::set_block_number(2);
+        assert_ok!(AssetRegistryModule::resubmit_asset(RuntimeOrigin::signed(1), 0));
+        System::set_block_number(3);
+        assert_ok!(AssetRegistryModule::approve_asset(RuntimeOrigin::root(), 0));
+
+        assert_eq!(
+            AssetRegistryModule::status_history(0).into_inner(),
+            vec![
+                StatusChange {
+                    block: 1,
+                    from: AssetStatus::Pending,
+                    to: AssetStatus::Pending,
+                    actor: Moderator::Signed(1),
+                    reason: Default::default(),
+                },
+                StatusChange {
+                    block: 1,
+                    from: AssetStatus::Pending,
+                    to: AssetStatus::Rejected,
+                    actor: Moderator::Signed(MODERATOR),
+                    reason: b"Spam".to_vec().try_into().unwrap(),
+                },
+                StatusChange {
+                    block: 2,
+                    from: AssetStatus::Rejected,
+                    to: AssetStatus::Pending,
+                    actor: Moderator::Signed(1),
+                    reason: Default::default(),
+                },
+                StatusChange {
+                    block: 3,
+                    from: AssetStatus::Pending,
+                    to: AssetStatus::Approved,
+                    actor: Moderator::Root,
+                    reason: Default::default(),
+                },
+            ]
+        );
+
+        // Only `MaxStatusHistory` changes are kept, the oldest are dropped
+        assert_ok!(AssetRegistryModule::suspend_asset(RuntimeOrigin::root(), 0, b"Spam".to_vec()));
+        assert_ok!(AssetRegistryModule::reinstate_asset(RuntimeOrigin::root(), 0, vec![]));
+        let history = AssetRegistryModule::status_history(0);
+        assert_eq!(history.len(), 4);
+        assert_eq!(history[0].to, AssetStatus::Pending);
+        assert_eq!(history[3].to, AssetStatus::Approved);
+        assert_eq!(history[3].from, AssetStatus::Suspended);
+    });
+}
+
+#[test]
+fn moderation_reasons_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetRegistryModule::register_asset(
+            RuntimeOrigin::signed(1),
+            b"Test Token".to_vec(),
+            b"TEST".to_vec(),
+            b"Description".to_vec(),
+            18,
+            1000,
+            b"{}".to_vec(),
+            true,
+            true,
+            true,
+        ));
+        let reason = vec![b'a'; <Test as crate::Config>::MaxReasonLength::get() as usize + 1];
+
+        assert_noop!(
+            AssetRegistryModule::reject_asset(RuntimeOrigin::root(), 0, reason),
+            Error::<Test>::ReasonTooLong
+        );
+    });
+}
@@ -1,13 +1,12 @@
-//! Autogenerated weights for pallet_asset_registry
+//! Weights for pallet_asset_registry
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-06-16, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/production/substrate
+//! THESE WEIGHTS ARE ESTIMATES AND HAVE NOT BEEN BENCHMARKED. Every function lists the storage it
+//! touches. Proof sizes follow the `MaxEncodedLen` bounds of the node runtime, execution times are
+//! conservative guesses and nothing was measured. Regenerate this file with
+//! `scripts/benchmark.sh` before deploying.
+
+// Command to regenerate:
+// ./target/release/substrate-dev-node
 // benchmark
 // pallet
 // --chain=dev
@@ -15,11 +14,9 @@
 // --repeat=20
 // --pallet=pallet_asset_registry
 // --extrinsic=*
-// --execution=wasm
 // --wasm-execution=compiled
 // --heap-pages=4096
 // --output=./pallets/asset-registry/src/weights.rs
-// --template=./.maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -34,8 +31,18 @@ pub trait WeightInfo {
     fn approve_asset() -> Weight;
     fn reject_asset() -> Weight;
     fn suspend_asset() -> Weight;
+    fn reinstate_asset() -> Weight;
+    fn resubmit_asset() -> Weight;
     fn update_asset_metadata() -> Weight;
-    fn transfer_ownership() -> Weight;
+    fn propose_ownership_transfer() -> Weight;
+    fn accept_ownership() -> Weight;
+    fn cancel_ownership_transfer() -> Weight;
+    fn remove_expired_ownership_transfer() -> Weight;
+    fn mint() -> Weight;
+    fn burn() -> Weight;
+    fn transfer() -> Weight;
+    fn approve() -> Weight;
+    fn transfer_from() -> Weight;
 }
 
 /// Weights for pallet_asset_registry using the Substrate node and recommended hardware.
@@ -44,123 +51,359 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: AssetRegistry NextAssetId (r:1 w:1)
     /// Proof: AssetRegistry NextAssetId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
     /// Storage: AssetRegistry AssetBySymbol (r:1 w:1)
-    /// Proof: AssetRegistry AssetBySymbol (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+    /// Proof Skipped: AssetRegistry AssetBySymbol (max_values: None, max_size: None, mode: Measured)
     /// Storage: AssetRegistry AssetCountByOwner (r:1 w:1)
-    /// Proof: AssetRegistry AssetCountByOwner (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Proof: AssetRegistry AssetCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
     /// Storage: AssetRegistry Assets (r:0 w:1)
-    /// Proof: AssetRegistry Assets (max_values: None, max_size: Some(2000), added: 4475, mode: MaxEncodedLen)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
     /// Storage: AssetRegistry AssetsByOwner (r:0 w:1)
-    /// Proof: AssetRegistry AssetsByOwner (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Proof: AssetRegistry AssetsByOwner (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+    /// Storage: Balances Holds (r:1 w:1)
+    /// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: AssetRegistry StatusHistory (r:1 w:1)
+    /// Proof: AssetRegistry StatusHistory (max_values: None, max_size: Some(29722), added: 32197, mode: MaxEncodedLen)
     fn register_asset() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `76`
-        //  Estimated: `3565`
-        // Minimum execution time: 25_000_000 picoseconds.
-        Weight::from_parts(26_000_000, 3565)
-            .saturating_add(T::DbWeight::get().reads(3_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+        //  Estimated: `43874`
+        Weight::from_parts(49_000_000, 43874)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
     }
 
     /// Storage: AssetRegistry Assets (r:1 w:1)
-    /// Proof: AssetRegistry Assets (max_values: None, max_size: Some(2000), added: 4475, mode: MaxEncodedLen)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry AssetBalances (r:1 w:1)
+    /// Proof: AssetRegistry AssetBalances (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    /// Storage: Balances Holds (r:1 w:1)
+    /// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: AssetRegistry StatusHistory (r:1 w:1)
+    /// Proof: AssetRegistry StatusHistory (max_values: None, max_size: Some(29722), added: 32197, mode: MaxEncodedLen)
     fn approve_asset() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `200`
-        //  Estimated: `4465`
-        // Minimum execution time: 15_000_000 picoseconds.
-        Weight::from_parts(16_000_000, 4465)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+        //  Estimated: `46586`
+        Weight::from_parts(26_000_000, 46586)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
     }
 
     /// Storage: AssetRegistry Assets (r:1 w:1)
-    /// Proof: AssetRegistry Assets (max_values: None, max_size: Some(2000), added: 4475, mode: MaxEncodedLen)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Balances Holds (r:1 w:1)
+    /// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: AssetRegistry StatusHistory (r:1 w:1)
+    /// Proof: AssetRegistry StatusHistory (max_values: None, max_size: Some(29722), added: 32197, mode: MaxEncodedLen)
     fn reject_asset() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `200`
-        //  Estimated: `4465`
-        // Minimum execution time: 15_000_000 picoseconds.
-        Weight::from_parts(16_000_000, 4465)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+        //  Estimated: `44027`
+        Weight::from_parts(46_000_000, 44027)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
     }
 
     /// Storage: AssetRegistry Assets (r:1 w:1)
-    /// Proof: AssetRegistry Assets (max_values: None, max_size: Some(2000), added: 4475, mode: MaxEncodedLen)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry StatusHistory (r:1 w:1)
+    /// Proof: AssetRegistry StatusHistory (max_values: None, max_size: Some(29722), added: 32197, mode: MaxEncodedLen)
     fn suspend_asset() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `200`
-        //  Estimated: `4465`
-        // Minimum execution time: 15_000_000 picoseconds.
-        Weight::from_parts(16_000_000, 4465)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+        //  Estimated: `38882`
+        Weight::from_parts(22_000_000, 38882)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:1)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry StatusHistory (r:1 w:1)
+    /// Proof: AssetRegistry StatusHistory (max_values: None, max_size: Some(29722), added: 32197, mode: MaxEncodedLen)
+    fn reinstate_asset() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `38882`
+        Weight::from_parts(22_000_000, 38882)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
     }
 
     /// Storage: AssetRegistry Assets (r:1 w:1)
-    /// Proof: AssetRegistry Assets (max_values: None, max_size: Some(2000), added: 4475, mode: MaxEncodedLen)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Balances Holds (r:1 w:1)
+    /// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: AssetRegistry StatusHistory (r:1 w:1)
+    /// Proof: AssetRegistry StatusHistory (max_values: None, max_size: Some(29722), added: 32197, mode: MaxEncodedLen)
+    fn resubmit_asset() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `44027`
+        Weight::from_parts(40_000_000, 44027)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:1)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Balances Holds (r:1 w:1)
+    /// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
     fn update_asset_metadata() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `11830`
+        Weight::from_parts(34_000_000, 11830)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:0)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry PendingOwnershipTransfers (r:0 w:1)
+    /// Proof: AssetRegistry PendingOwnershipTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    fn propose_ownership_transfer() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `200`
-        //  Estimated: `4465`
-        // Minimum execution time: 15_000_000 picoseconds.
-        Weight::from_parts(16_000_000, 4465)
+        //  Estimated: `6685`
+        Weight::from_parts(18_000_000, 6685)
             .saturating_add(T::DbWeight::get().reads(1_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 
+    /// Storage: AssetRegistry PendingOwnershipTransfers (r:1 w:1)
+    /// Proof: AssetRegistry PendingOwnershipTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
     /// Storage: AssetRegistry Assets (r:1 w:1)
-    /// Proof: AssetRegistry Assets (max_values: None, max_size: Some(2000), added: 4475, mode: MaxEncodedLen)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
     /// Storage: AssetRegistry AssetCountByOwner (r:2 w:2)
-    /// Proof: AssetRegistry AssetCountByOwner (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Proof: AssetRegistry AssetCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
     /// Storage: AssetRegistry AssetsByOwner (r:0 w:2)
-    /// Proof: AssetRegistry AssetsByOwner (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
-    fn transfer_ownership() -> Weight {
+    /// Proof: AssetRegistry AssetsByOwner (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+    /// Storage: Balances Holds (r:2 w:2)
+    /// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn accept_ownership() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `300`
-        //  Estimated: `6012`
-        // Minimum execution time: 30_000_000 picoseconds.
-        Weight::from_parts(31_000_000, 6012)
+        //  Estimated: `24560`
+        Weight::from_parts(67_000_000, 24560)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:0)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry PendingOwnershipTransfers (r:1 w:1)
+    /// Proof: AssetRegistry PendingOwnershipTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    fn cancel_ownership_transfer() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `9216`
+        Weight::from_parts(19_000_000, 9216)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: AssetRegistry PendingOwnershipTransfers (r:1 w:1)
+    /// Proof: AssetRegistry PendingOwnershipTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    fn remove_expired_ownership_transfer() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `3521`
+        Weight::from_parts(15_000_000, 3521)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:1)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry AssetBalances (r:1 w:1)
+    /// Proof: AssetRegistry AssetBalances (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    fn mint() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `9244`
+        Weight::from_parts(23_000_000, 9244)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:1)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry AssetBalances (r:1 w:1)
+    /// Proof: AssetRegistry AssetBalances (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    fn burn() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `9244`
+        Weight::from_parts(23_000_000, 9244)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:0)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry AssetBalances (r:2 w:2)
+    /// Proof: AssetRegistry AssetBalances (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    fn transfer() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `11803`
+        Weight::from_parts(27_000_000, 11803)
             .saturating_add(T::DbWeight::get().reads(3_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:0)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry Allowances (r:0 w:1)
+    /// Proof: AssetRegistry Allowances (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+    fn approve() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `6685`
+        Weight::from_parts(18_000_000, 6685)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: AssetRegistry Assets (r:1 w:0)
+    /// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+    /// Storage: AssetRegistry Allowances (r:1 w:1)
+    /// Proof: AssetRegistry Allowances (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+    /// Storage: AssetRegistry AssetBalances (r:2 w:2)
+    /// Proof: AssetRegistry AssetBalances (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    fn transfer_from() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `14410`
+        Weight::from_parts(33_000_000, 14410)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
     }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
     fn register_asset() -> Weight {
-        Weight::from_parts(26_000_000, 3565)
-            .saturating_add(RocksDbWeight::get().reads(3_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+        // Estimated, not benchmarked.
+        Weight::from_parts(49_000_000, 43874)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
     }
 
     fn approve_asset() -> Weight {
-        Weight::from_parts(16_000_000, 4465)
-            .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+        // Estimated, not benchmarked.
+        Weight::from_parts(26_000_000, 46586)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
     }
 
     fn reject_asset() -> Weight {
-        Weight::from_parts(16_000_000, 4465)
-            .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+        // Estimated, not benchmarked.
+        Weight::from_parts(46_000_000, 44027)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
 
     fn suspend_asset() -> Weight {
-        Weight::from_parts(16_000_000, 4465)
+        // Estimated, not benchmarked.
+        Weight::from_parts(22_000_000, 38882)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn reinstate_asset() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(22_000_000, 38882)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn resubmit_asset() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(40_000_000, 44027)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    fn update_asset_metadata() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(34_000_000, 11830)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn propose_ownership_transfer() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(18_000_000, 6685)
             .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 
-    fn update_asset_metadata() -> Weight {
-        Weight::from_parts(16_000_000, 4465)
+    fn accept_ownership() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(67_000_000, 24560)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+
+    fn cancel_ownership_transfer() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(19_000_000, 9216)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn remove_expired_ownership_transfer() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(15_000_000, 3521)
             .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 
-    fn transfer_ownership() -> Weight {
-        Weight::from_parts(31_000_000, 6012)
+    fn mint() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(23_000_000, 9244)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn burn() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(23_000_000, 9244)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn transfer() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(27_000_000, 11803)
             .saturating_add(RocksDbWeight::get().reads(3_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn approve() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(18_000_000, 6685)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn transfer_from() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(33_000_000, 14410)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+//! Runtime API definition for the voting pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_voting::ProposalInfo;
+
+sp_api::decl_runtime_apis! {
+    /// Read access to the proposals and tallies of the voting pallet
+    pub trait VotingApi<AccountId, Balance, BlockNumber, Call, Description, Options>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Call: Codec,
+        Description: Codec,
+        Options: Codec,
+    {
+        /// Proposal with the given ID
+        fn proposal(
+            proposal_id: u32,
+        ) -> Option<ProposalInfo<AccountId, Balance, BlockNumber, Call, Description, Options>>;
+
+        /// Vote weight of each option of a proposal
+        fn results(proposal_id: u32) -> Vec<Balance>;
+
+        /// Active proposals with an ID in `start..start + limit`
+        fn active_proposals(
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, ProposalInfo<AccountId, Balance, BlockNumber, Call, Description, Options>)>;
+
+        /// Whether `who` has voted on a proposal
+        fn has_voted(proposal_id: u32, who: AccountId) -> bool;
+
+        /// Proposals `who` has voted on, most recent first, one page of
+        /// `pallet_voting::VOTER_HISTORY_PAGE_SIZE` at a time
+        fn voter_history(who: AccountId, page: u32) -> Vec<u32>;
+    }
+}
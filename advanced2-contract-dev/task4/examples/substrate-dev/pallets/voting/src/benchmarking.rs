@@ -4,45 +4,161 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Voting;
+use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+    traits::{Currency, Hooks},
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Hash, IdentifyAccount, Saturating, Zero};
+use sp_std::{boxed::Box, vec::Vec};
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, 0);
+    T::Currency::make_free_balance_be(&who, T::Currency::minimum_balance() * 1000u32.into());
+    who
+}
+
+/// Fund `who` to reserve the deposits of as many proposals as a block index holds
+fn fund_proposer<T: Config>(who: &T::AccountId) {
+    let proposals = T::MaxProposalsEndingPerBlock::get()
+        .max(T::MaxProposalsStartingPerBlock::get())
+        .max(T::MaxRewardsExpiringPerBlock::get());
+    let deposits = T::ProposalDeposit::get().saturating_mul(proposals.into());
+    T::Currency::make_free_balance_be(
+        who,
+        (T::Currency::minimum_balance() * 1000u32.into()).saturating_add(deposits),
+    );
+}
+
+/// Parameters of a single-choice proposal open to everyone for `MinVotingPeriod` blocks
+fn proposal_params<T: Config>() -> ProposalParamsOf<T> {
+    ProposalParams {
+        description: b"Test proposal for benchmarking".to_vec(),
+        options: vec![b"Option A".to_vec(), b"Option B".to_vec()],
+        voting_period: T::MinVotingPeriod::get(),
+        start_delay: Zero::zero(),
+        quorum: T::MinQuorum::get(),
+        approval_threshold: T::MinApprovalThreshold::get(),
+        call: None,
+        secret_ballot: false,
+        mode: VotingMode::SingleChoice,
+        eligibility: EligibilityRule::Open,
+        seats: 1,
+    }
+}
+
+/// Fund `voter` with most of the issuance and return the stake it votes with
+fn fund_majority<T: Config>(voter: &T::AccountId) -> BalanceOf<T> {
+    let balance = T::Currency::total_issuance()
+        .max(T::Currency::minimum_balance() * 1000u32.into())
+        .saturating_mul(100u32.into());
+    T::Currency::make_free_balance_be(voter, balance);
+    balance / 10u32.into() * 9u32.into()
+}
+
+/// Create a proposal with a call and an earmarked reward that `voter` passes with `stake`
+///
+/// Finalizing it releases the deposit, opens a reward pool and schedules the call, the heaviest
+/// outcome. Returns the block the proposal can be finalized in.
+fn passed_proposal<T: Config>(
+    proposer: &T::AccountId,
+    voter: &T::AccountId,
+    stake: BalanceOf<T>,
+) -> BlockNumberFor<T> {
+    let proposal_id = Voting::<T>::next_proposal_id();
+    // A remark too long to inline, noted as a distinct preimage for every proposal
+    let mut remark = proposal_id.encode();
+    remark.resize(T::MaxCallLength::get().saturating_sub(16).max(4) as usize, 0);
+    let call: CallOf<T> = frame_system::Call::<T>::remark { remark }.into();
+    let voting_period = T::MinVotingPeriod::get().max(T::MinEnactmentPeriod::get());
+    let _ = Voting::<T>::create_proposal(
+        RawOrigin::Signed(proposer.clone()).into(),
+        ProposalParams {
+            voting_period,
+            quorum: Voting::<T>::enactment_quorum().max(T::MinQuorum::get()),
+            call: Some(Box::new(call)),
+            ..proposal_params::<T>()
+        },
+    );
+    let _ = Voting::<T>::fund_reward(
+        RawOrigin::Signed(voter.clone()).into(),
+        proposal_id,
+        T::Currency::minimum_balance() * 100u32.into(),
+    );
+    let _ = Voting::<T>::vote(
+        RawOrigin::Signed(voter.clone()).into(),
+        proposal_id,
+        0,
+        stake,
+        Conviction::Locked1x,
+    );
+    frame_system::Pallet::<T>::block_number() + voting_period + 1u32.into()
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn create_proposal() {
+    fn create_proposal(
+        a: Linear<0, { T::MaxAllowlist::get() }>,
+        c: Linear<0, { T::MaxCallLength::get() }>,
+    ) {
         let caller: T::AccountId = whitelisted_caller();
-        let description = b"Test proposal for benchmarking".to_vec();
-        let options = vec![b"Option A".to_vec(), b"Option B".to_vec(), b"Option C".to_vec()];
-        let voting_period = T::MinVotingPeriod::get();
+        fund_proposer::<T>(&caller);
+        // Setup: a remark that encodes to at most `c` bytes, noted as a preimage if too long to
+        // inline
+        let remark = vec![0u8; c.saturating_sub(16) as usize];
+        let call: CallOf<T> = frame_system::Call::<T>::remark { remark }.into();
+        let allowlist: BoundedVec<_, _> =
+            (0..a).map(|i| account("voter", i, 0)).collect::<Vec<_>>().try_into().unwrap();
+        let params = ProposalParams {
+            options: vec![b"Option A".to_vec(), b"Option B".to_vec(), b"Option C".to_vec()],
+            voting_period: T::MinVotingPeriod::get().max(T::MinEnactmentPeriod::get()),
+            start_delay: T::MaxStartDelay::get(),
+            quorum: Voting::<T>::enactment_quorum().max(T::MinQuorum::get()),
+            call: Some(Box::new(call)),
+            eligibility: EligibilityRule::Allowlist(allowlist),
+            seats: 2,
+            ..proposal_params::<T>()
+        };
 
         #[extrinsic_call]
-        create_proposal(RawOrigin::Signed(caller), description, options, voting_period);
+        create_proposal(RawOrigin::Signed(caller), params);
 
         assert_eq!(Voting::<T>::next_proposal_id(), 1);
     }
 
     #[benchmark]
-    fn vote() {
+    fn vote(d: Linear<0, { T::MaxDelegators::get() }>) {
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
         let proposer: T::AccountId = account("proposer", 0, 0);
-        
+        fund_proposer::<T>(&proposer);
+
         // Setup: create a proposal first
-        let description = b"Test proposal for benchmarking".to_vec();
-        let options = vec![b"Option A".to_vec(), b"Option B".to_vec()];
-        let voting_period = T::MinVotingPeriod::get();
-        
         let _ = Voting::<T>::create_proposal(
             RawOrigin::Signed(proposer).into(),
-            description,
-            options,
-            voting_period,
+            proposal_params::<T>(),
         );
 
+        // Setup: `d` accounts delegating to the voter
+        for i in 0..d {
+            let delegator = funded_account::<T>("delegator", i);
+            let _ = Voting::<T>::delegate(
+                RawOrigin::Signed(delegator).into(),
+                caller.clone(),
+                DelegationScope::All,
+                balance,
+                Conviction::Locked1x,
+            );
+        }
+
         #[extrinsic_call]
-        vote(RawOrigin::Signed(caller.clone()), 0, 0);
+        vote(RawOrigin::Signed(caller.clone()), 0, 0, balance, Conviction::Locked6x);
 
         assert!(Voting::<T>::votes(0, &caller).is_some());
     }
@@ -51,52 +167,647 @@ mod benchmarks {
     fn end_proposal() {
         let caller: T::AccountId = whitelisted_caller();
         let proposer: T::AccountId = account("proposer", 0, 0);
-        
+        fund_proposer::<T>(&proposer);
+        let voter: T::AccountId = account("voter", 0, 0);
+        let stake = fund_majority::<T>(&voter);
+
+        // Setup: a passed proposal with a call and a reward pool to open
+        let end_block = passed_proposal::<T>(&proposer, &voter, stake);
+        frame_system::Pallet::<T>::set_block_number(end_block);
+
+        #[extrinsic_call]
+        end_proposal(RawOrigin::Signed(caller), 0);
+
+        let proposal = Voting::<T>::proposals(0).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        assert!(Voting::<T>::enactment_task(0).is_some());
+        assert!(Voting::<T>::reward_pool(0).is_some());
+    }
+
+    #[benchmark]
+    fn cancel_proposal() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund_proposer::<T>(&caller);
+
         // Setup: create a proposal first
-        let description = b"Test proposal for benchmarking".to_vec();
-        let options = vec![b"Option A".to_vec(), b"Option B".to_vec()];
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(caller.clone()).into(),
+            proposal_params::<T>(),
+        );
+
+        #[extrinsic_call]
+        cancel_proposal(RawOrigin::Signed(caller), 0);
+
+        let proposal = Voting::<T>::proposals(0).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Cancelled);
+    }
+
+    #[benchmark]
+    fn unlock() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund_proposer::<T>(&caller);
+        let voter = funded_account::<T>("voter", 0);
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+
         let voting_period = T::MinVotingPeriod::get();
-        
+
+        // Setup: vote on a proposal and let it end
         let _ = Voting::<T>::create_proposal(
-            RawOrigin::Signed(proposer).into(),
-            description,
-            options,
-            voting_period,
+            RawOrigin::Signed(caller.clone()).into(),
+            proposal_params::<T>(),
+        );
+        let _ = Voting::<T>::vote(
+            RawOrigin::Signed(voter.clone()).into(),
+            0,
+            0,
+            balance,
+            Conviction::None,
         );
 
-        // Fast forward past voting period
         let current_block = frame_system::Pallet::<T>::block_number();
         let end_block = current_block + voting_period + 1u32.into();
         frame_system::Pallet::<T>::set_block_number(end_block);
 
         #[extrinsic_call]
-        end_proposal(RawOrigin::Signed(caller), 0);
+        unlock(RawOrigin::Signed(caller), voter.clone());
 
-        let proposal = Voting::<T>::proposals(0).unwrap();
-        assert!(!proposal.is_active);
+        assert!(Voting::<T>::vote_locks(&voter).is_empty());
     }
 
     #[benchmark]
-    fn cancel_proposal() {
+    fn on_initialize(p: Linear<0, { T::MaxProposalsEndingPerBlock::get() }>) {
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+        let voter: T::AccountId = account("voter", 0, 0);
+        let stake = fund_majority::<T>(&voter);
+
+        // Setup: `p` passed proposals with a call and a reward pool, all ending in the same block
+        let mut next_block = frame_system::Pallet::<T>::block_number();
+        for _ in 0..p {
+            next_block = passed_proposal::<T>(&proposer, &voter, stake);
+        }
+        frame_system::Pallet::<T>::set_block_number(next_block);
+
+        #[block]
+        {
+            Voting::<T>::on_initialize(next_block);
+        }
+
+        for proposal_id in 0..p {
+            let proposal = Voting::<T>::proposals(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Passed);
+            assert_eq!(
+                Voting::<T>::reward_pool(proposal_id).is_some(),
+                proposal_id < T::MaxRewardsExpiringPerBlock::get()
+            );
+        }
+    }
+
+    #[benchmark]
+    fn open_proposals(p: Linear<0, { T::MaxProposalsStartingPerBlock::get() }>) {
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+        let start_delay = T::MaxStartDelay::get();
+
+        // Setup: `p` proposals that all start in the same block
+        for _ in 0..p {
+            let _ = Voting::<T>::create_proposal(
+                RawOrigin::Signed(proposer.clone()).into(),
+                ProposalParams {
+                    start_delay,
+                    ..proposal_params::<T>()
+                },
+            );
+        }
+
+        let start_block = frame_system::Pallet::<T>::block_number() + start_delay;
+        frame_system::Pallet::<T>::set_block_number(start_block);
+
+        #[block]
+        {
+            Voting::<T>::on_initialize(start_block);
+        }
+
+        for proposal_id in 0..p {
+            let proposal = Voting::<T>::proposals(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Active);
+        }
+    }
+
+    #[benchmark]
+    fn expire_reward_pools(p: Linear<0, { T::MaxRewardsExpiringPerBlock::get() }>) {
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+        let amount = T::Currency::minimum_balance() * 100u32.into();
+        let expires_at = frame_system::Pallet::<T>::block_number() + T::RewardClaimPeriod::get();
+
+        // Setup: `p` reward pools that all expire in the same block
+        for proposal_id in 0..p {
+            let _ = Voting::<T>::create_proposal(
+                RawOrigin::Signed(proposer.clone()).into(),
+                proposal_params::<T>(),
+            );
+            RewardPools::<T>::insert(
+                proposal_id,
+                RewardPool {
+                    amount,
+                    claimed: Zero::zero(),
+                    turnout: amount,
+                    winning_option: Some(0),
+                    expires_at,
+                },
+            );
+            RewardsExpiringAt::<T>::mutate(expires_at, |ids| ids.try_push(proposal_id)).unwrap();
+        }
+        frame_system::Pallet::<T>::set_block_number(expires_at);
+
+        #[block]
+        {
+            Voting::<T>::on_initialize(expires_at);
+        }
+
+        for proposal_id in 0..p {
+            assert!(Voting::<T>::reward_pool(proposal_id).is_none());
+            assert!(Voting::<T>::cleanup_queue(proposal_id).is_some());
+        }
+    }
+
+    #[benchmark]
+    fn delegate() {
         let caller: T::AccountId = whitelisted_caller();
-        
-        // Setup: create a proposal first
-        let description = b"Test proposal for benchmarking".to_vec();
-        let options = vec![b"Option A".to_vec(), b"Option B".to_vec()];
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+
+        // Setup: a chain of `MaxDelegationDepth` delegations behind the target
+        let target = funded_account::<T>("delegate", 0);
+        let mut to = target.clone();
+        for i in 1..T::MaxDelegationDepth::get() {
+            let next = funded_account::<T>("delegate", i);
+            let _ = Voting::<T>::delegate(
+                RawOrigin::Signed(to.clone()).into(),
+                next.clone(),
+                DelegationScope::All,
+                balance,
+                Conviction::Locked1x,
+            );
+            to = next;
+        }
+
+        #[extrinsic_call]
+        delegate(RawOrigin::Signed(caller.clone()), target, DelegationScope::All, balance, Conviction::Locked6x);
+
+        assert!(Voting::<T>::delegation_of(&caller, DelegationScope::All).is_some());
+    }
+
+    #[benchmark]
+    fn undelegate() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let target = funded_account::<T>("delegate", 0);
+
+        let _ = Voting::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            target,
+            DelegationScope::All,
+            balance,
+            Conviction::Locked6x,
+        );
+
+        #[extrinsic_call]
+        undelegate(RawOrigin::Signed(caller.clone()), DelegationScope::All);
+
+        assert!(Voting::<T>::delegations(&caller).is_empty());
+    }
+
+    #[benchmark]
+    fn commit_vote() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            ProposalParams {
+                secret_ballot: true,
+                ..proposal_params::<T>()
+            },
+        );
+        let hash = T::Hashing::hash_of(&(0u32, [0u8; 32]));
+
+        #[extrinsic_call]
+        commit_vote(RawOrigin::Signed(caller.clone()), 0, hash, balance, Conviction::Locked6x);
+
+        assert!(Voting::<T>::commitments(0, &caller).is_some());
+    }
+
+    #[benchmark]
+    fn reveal_vote(d: Linear<0, { T::MaxDelegators::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
         let voting_period = T::MinVotingPeriod::get();
-        
+
         let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            ProposalParams {
+                secret_ballot: true,
+                ..proposal_params::<T>()
+            },
+        );
+
+        // Setup: `d` accounts delegating to the voter
+        for i in 0..d {
+            let delegator = funded_account::<T>("delegator", i);
+            let _ = Voting::<T>::delegate(
+                RawOrigin::Signed(delegator).into(),
+                caller.clone(),
+                DelegationScope::All,
+                balance,
+                Conviction::Locked1x,
+            );
+        }
+
+        let salt = [7u8; 32];
+        let _ = Voting::<T>::commit_vote(
             RawOrigin::Signed(caller.clone()).into(),
-            description,
-            options,
-            voting_period,
+            0,
+            T::Hashing::hash_of(&(0u32, salt)),
+            balance,
+            Conviction::Locked6x,
         );
 
+        let current_block = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(current_block + voting_period + 1u32.into());
+
         #[extrinsic_call]
-        cancel_proposal(RawOrigin::Signed(caller), 0);
+        reveal_vote(RawOrigin::Signed(caller.clone()), 0, 0, salt);
+
+        assert!(Voting::<T>::votes(0, &caller).is_some());
+    }
+
+    #[benchmark]
+    fn reap_commitment() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund_proposer::<T>(&caller);
+        let voter = funded_account::<T>("voter", 0);
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let voting_period = T::MinVotingPeriod::get();
+
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(caller.clone()).into(),
+            ProposalParams {
+                secret_ballot: true,
+                ..proposal_params::<T>()
+            },
+        );
+        let _ = Voting::<T>::commit_vote(
+            RawOrigin::Signed(voter.clone()).into(),
+            0,
+            T::Hashing::hash_of(&(0u32, [0u8; 32])),
+            balance,
+            Conviction::None,
+        );
+
+        let current_block = frame_system::Pallet::<T>::block_number();
+        let reveal_end = current_block + voting_period + T::RevealPeriod::get();
+        frame_system::Pallet::<T>::set_block_number(reveal_end + 1u32.into());
+
+        #[extrinsic_call]
+        reap_commitment(RawOrigin::Signed(caller), 0, voter.clone());
+
+        assert!(Voting::<T>::commitments(0, &voter).is_none());
+    }
+
+    #[benchmark]
+    fn vote_approval(
+        o: Linear<1, { T::MaxOptions::get() }>,
+        d: Linear<0, { T::MaxDelegators::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+
+        let options = (0..T::MaxOptions::get()).map(|i| vec![i as u8]).collect::<Vec<_>>();
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            ProposalParams {
+                options,
+                mode: VotingMode::Approval,
+                ..proposal_params::<T>()
+            },
+        );
+
+        // Setup: `d` accounts delegating to the voter
+        for i in 0..d {
+            let delegator = funded_account::<T>("delegator", i);
+            let _ = Voting::<T>::delegate(
+                RawOrigin::Signed(delegator).into(),
+                caller.clone(),
+                DelegationScope::All,
+                balance,
+                Conviction::Locked1x,
+            );
+        }
+
+        #[extrinsic_call]
+        vote_approval(RawOrigin::Signed(caller.clone()), 0, (0..o).collect(), balance, Conviction::Locked6x);
+
+        assert_eq!(Voting::<T>::ballots(0, &caller).unwrap().len() as u32, o);
+    }
+
+    #[benchmark]
+    fn vote_ranked(
+        o: Linear<1, { T::MaxOptions::get() }>,
+        d: Linear<0, { T::MaxDelegators::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+
+        let options = (0..T::MaxOptions::get()).map(|i| vec![i as u8]).collect::<Vec<_>>();
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            ProposalParams {
+                options,
+                mode: VotingMode::RankedChoice,
+                ..proposal_params::<T>()
+            },
+        );
+
+        // Setup: the stored rankings are full except for one slot
+        let rankings = (1..T::MaxRankings::get())
+            .map(|i| {
+                let ranking = BoundedVec::truncate_from(vec![i % T::MaxOptions::get()]);
+                (ranking, balance)
+            })
+            .collect::<Vec<_>>();
+        RankedBallots::<T>::insert(0, BoundedVec::truncate_from(rankings));
+
+        for i in 0..d {
+            let delegator = funded_account::<T>("delegator", i);
+            let _ = Voting::<T>::delegate(
+                RawOrigin::Signed(delegator).into(),
+                caller.clone(),
+                DelegationScope::All,
+                balance,
+                Conviction::Locked1x,
+            );
+        }
+
+        #[extrinsic_call]
+        vote_ranked(RawOrigin::Signed(caller.clone()), 0, (0..o).rev().collect(), balance, Conviction::Locked6x);
+
+        assert_eq!(Voting::<T>::ballots(0, &caller).unwrap().len() as u32, o);
+    }
+
+    #[benchmark]
+    fn instant_runoff(
+        o: Linear<2, { T::MaxOptions::get() }>,
+        r: Linear<1, { T::MaxRankings::get() }>,
+    ) {
+        let weight = T::Currency::minimum_balance();
+
+        // Setup: `r` rankings of all options that leave the options level for as long as possible
+        let rankings = (0..r)
+            .map(|i| {
+                let ranking = (0..o).map(|j| (i + j) % o).collect::<Vec<_>>();
+                (BoundedVec::truncate_from(ranking), weight)
+            })
+            .collect::<Vec<_>>();
+        RankedBallots::<T>::insert(0, BoundedVec::truncate_from(rankings));
+
+        let tallies;
+        #[block]
+        {
+            tallies = Voting::<T>::instant_runoff(0, o);
+        }
+
+        assert_eq!(tallies.len() as u32, o);
+    }
+
+    #[benchmark]
+    fn submit_signed_votes(
+        v: Linear<1, { T::MaxSignedVotes::get() }>,
+        d: Linear<0, { T::MaxDelegators::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            proposal_params::<T>(),
+        );
+
+        // Setup: `v` votes signed off-chain, each with `d` accounts delegating to the voter
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+        let payload =
+            (SIGNED_VOTE_CONTEXT, genesis_hash, 0u32, 0u32, balance, Conviction::Locked1x, 0u32)
+                .encode();
+        let mut votes = Vec::new();
+        for i in 0..v {
+            let public = T::BenchmarkHelper::create_public();
+            let signature = T::BenchmarkHelper::sign(&public, &payload);
+            let voter = public.into_account();
+            T::Currency::make_free_balance_be(&voter, T::Currency::minimum_balance() * 1000u32.into());
+            for j in 0..d {
+                let delegator = funded_account::<T>("delegator", i * d + j);
+                let _ = Voting::<T>::delegate(
+                    RawOrigin::Signed(delegator).into(),
+                    voter.clone(),
+                    DelegationScope::All,
+                    balance,
+                    Conviction::Locked1x,
+                );
+            }
+            votes.push((voter, 0, 0, balance, Conviction::Locked1x, 0, signature));
+        }
+        let voter = votes[0].0.clone();
+
+        #[extrinsic_call]
+        submit_signed_votes(RawOrigin::Signed(caller), votes);
+
+        assert!(Voting::<T>::votes(0, &voter).is_some());
+        assert_eq!(Voting::<T>::signed_vote_nonce(&voter), 1);
+    }
+
+    #[benchmark]
+    fn veto_proposal() -> Result<(), BenchmarkError> {
+        let voter = funded_account::<T>("voter", 0);
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+        // A voter holding two thirds of the issuance meets the quorum of a call on its own
+        T::Currency::make_free_balance_be(&voter, T::Currency::total_issuance() * 2u32.into());
+        let balance = Voting::<T>::enactment_quorum().max(T::MinQuorum::get());
+        let call: CallOf<T> = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let voting_period = T::MinVotingPeriod::get().max(T::MinEnactmentPeriod::get());
+
+        // Setup: a passed proposal whose call is scheduled, the worst case
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            ProposalParams {
+                voting_period,
+                quorum: balance,
+                call: Some(Box::new(call)),
+                ..proposal_params::<T>()
+            },
+        );
+        let _ = Voting::<T>::vote(
+            RawOrigin::Signed(voter).into(),
+            0,
+            0,
+            balance,
+            Conviction::Locked1x,
+        );
+        let end_block = frame_system::Pallet::<T>::block_number() + voting_period + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(end_block);
+        Voting::<T>::finalize_proposal(0)?;
+        assert!(Voting::<T>::enactment_task(0).is_some());
+
+        let origin =
+            T::VetoOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let reason = vec![0u8; T::MaxVetoReasonLength::get() as usize];
+
+        #[extrinsic_call]
+        veto_proposal(origin as T::RuntimeOrigin, 0, reason);
 
         let proposal = Voting::<T>::proposals(0).unwrap();
-        assert!(!proposal.is_active);
+        assert_eq!(proposal.status, ProposalStatus::Vetoed);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_reward() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let balance = T::Currency::minimum_balance() * 100u32.into();
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            proposal_params::<T>(),
+        );
+        let _ = Voting::<T>::vote(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            0,
+            balance,
+            Conviction::Locked1x,
+        );
+
+        // Setup: a funded pot and a reward pool for the proposal
+        let amount = T::Currency::minimum_balance() * 100u32.into();
+        T::Currency::make_free_balance_be(&Voting::<T>::reward_pot(), amount * 2u32.into());
+        RewardPools::<T>::insert(
+            0,
+            RewardPool {
+                amount,
+                claimed: Zero::zero(),
+                turnout: balance,
+                winning_option: Some(0),
+                expires_at: T::RewardClaimPeriod::get(),
+            },
+        );
+        OutstandingRewards::<T>::put(amount);
+
+        #[extrinsic_call]
+        claim_reward(RawOrigin::Signed(caller.clone()), 0);
+
+        assert_eq!(Voting::<T>::reward_claim(0, &caller), Some(amount));
+    }
+
+    #[benchmark]
+    fn fund_reward() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 1000u32.into());
+        let amount = T::Currency::minimum_balance() * 100u32.into();
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        fund_proposer::<T>(&proposer);
+        let _ = Voting::<T>::create_proposal(
+            RawOrigin::Signed(proposer).into(),
+            proposal_params::<T>(),
+        );
+
+        #[extrinsic_call]
+        fund_reward(RawOrigin::Signed(caller), 0, amount);
+
+        assert_eq!(Voting::<T>::reward_funds(0), amount);
+    }
+
+    #[benchmark]
+    fn clear_votes(k: Linear<0, 1000>) {
+        let weight = T::Currency::minimum_balance();
+
+        // Setup: an ended proposal with `k` votes queued for cleanup
+        for i in 0..k {
+            let vote = VoteInfo {
+                option_index: 0,
+                balance: weight,
+                conviction: Conviction::None,
+                weight,
+                delegated: Zero::zero(),
+            };
+            Votes::<T>::insert(0, account::<T::AccountId>("voter", i, 0), vote);
+        }
+        Voting::<T>::queue_cleanup(0, ProposalStatus::Passed, Some(0));
+
+        #[block]
+        {
+            let (proposal_id, mut task) = CleanupQueue::<T>::iter().next().unwrap();
+            let result = Votes::<T>::clear_prefix(proposal_id, k, None);
+            task.votes = result.unique;
+            CleanupQueue::<T>::insert(proposal_id, task);
+        }
+
+        assert_eq!(Voting::<T>::cleanup_queue(0).unwrap().votes, k);
+    }
+
+    #[benchmark]
+    fn archive_proposal() {
+        RankedBallots::<T>::insert(0, BoundedVec::default());
+        Turnout::<T>::insert(0, T::Currency::minimum_balance());
+        VetoReasons::<T>::insert(0, BoundedVec::default());
+        Voting::<T>::queue_cleanup(0, ProposalStatus::Vetoed, None);
+        let task = Voting::<T>::cleanup_queue(0).unwrap();
+
+        #[block]
+        {
+            assert!(!Voting::<T>::enactment_pending(0));
+            Voting::<T>::archive(0, task);
+        }
+
+        assert!(Voting::<T>::cleanup_queue(0).is_none());
+        assert!(Voting::<T>::veto_reason(0).is_none());
+    }
+
+    #[benchmark]
+    fn note_balance_change() {
+        let who: T::AccountId = account("holder", 0, 0);
+        let balance = T::Currency::minimum_balance();
+
+        // Setup: a full list of checkpoints, so the two oldest are merged
+        let max = T::MaxCheckpoints::get();
+        for block in 1..=max {
+            frame_system::Pallet::<T>::set_block_number(block.into());
+            Voting::<T>::note_balance_change(&who, balance);
+        }
+        frame_system::Pallet::<T>::set_block_number((max + 1).into());
+
+        #[block]
+        {
+            Voting::<T>::note_balance_change(&who, balance);
+        }
+
+        assert_eq!(Voting::<T>::native_checkpoints(&who).len() as u32, max);
     }
 
     impl_benchmark_test_suite!(Voting, crate::mock::new_test_ext(), crate::mock::Test);
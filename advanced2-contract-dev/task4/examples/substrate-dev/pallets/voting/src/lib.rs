@@ -14,6 +14,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
@@ -23,13 +24,164 @@ pub mod pallet {
     use frame_support::{
         dispatch::{DispatchResult, DispatchResultWithPostInfo},
         pallet_prelude::*,
-        traits::{Get, Randomness},
+        traits::{
+            schedule::{v3::Anon as ScheduleAnon, DispatchTime},
+            Bounded, Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier,
+            LockableCurrency, OriginTrait, QueryPreimage, ReservableCurrency, StoredMap,
+            StorePreimage, WithdrawReasons,
+        },
+        PalletId,
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{Saturating, Zero};
-    use sp_std::vec::Vec;
+    use sp_runtime::{
+        traits::{
+            AccountIdConversion, AtLeast32BitUnsigned, Hash, IdentifyAccount, One, Saturating,
+            Verify, Zero,
+        },
+        Perbill,
+    };
+    use sp_std::{boxed::Box, cmp::Reverse, vec::Vec};
+
+    /// Identifier of the lock placed on balances backing a vote
+    pub const VOTING_ID: LockIdentifier = *b"pvoting ";
+
+    /// Balance type of the currency used for voting
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Call that can be attached to a proposal
+    pub type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
+
+    /// Proposal call, stored inline or as a preimage hash
+    pub type BoundedCallOf<T> = Bounded<CallOf<T>>;
+
+    /// Origin the call of a passed proposal is dispatched with
+    pub type PalletsOriginOf<T> =
+        <<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+    /// Address of the scheduled enactment of a passed proposal
+    pub type TaskAddressOf<T> = <<T as Config>::Scheduler as ScheduleAnon<
+        BlockNumberFor<T>,
+        CallOf<T>,
+        PalletsOriginOf<T>,
+    >>::Address;
+
+    /// Reason given for vetoing a proposal
+    pub type VetoReasonOf<T> = BoundedVec<u8, <T as Config>::MaxVetoReasonLength>;
+
+    /// Balances of an account before the blocks they changed in, oldest first
+    pub type CheckpointsOf<T, Balance> =
+        BoundedVec<(BlockNumberFor<T>, Balance), <T as Config>::MaxCheckpoints>;
+
+    /// Proposal description, at most `MaxDescriptionLength` bytes
+    pub type DescriptionOf<T> = BoundedVec<u8, <T as Config>::MaxDescriptionLength>;
+
+    /// Proposal options, at most `MaxOptions` of at most `MaxOptionLength` bytes each
+    pub type OptionsOf<T> =
+        BoundedVec<BoundedVec<u8, <T as Config>::MaxOptionLength>, <T as Config>::MaxOptions>;
+
+    /// Proposal information as stored by this pallet
+    pub type ProposalInfoOf<T> = ProposalInfo<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        BoundedCallOf<T>,
+        DescriptionOf<T>,
+        OptionsOf<T>,
+    >;
+
+    /// Parameters of a proposal as submitted to `create_proposal`
+    pub type ProposalParamsOf<T> = ProposalParams<
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        Box<CallOf<T>>,
+        EligibilityRuleOf<T>,
+    >;
+
+    /// Eligibility rule as stored by this pallet
+    pub type EligibilityRuleOf<T> = EligibilityRule<
+        BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxAllowlist>,
+    >;
+
+    /// Vote signed off-chain by `voter`:
+    /// `(voter, proposal_id, option_index, balance, conviction, nonce, signature)`
+    pub type SignedVoteOf<T> = (
+        <T as frame_system::Config>::AccountId,
+        u32,
+        u32,
+        BalanceOf<T>,
+        Conviction,
+        u32,
+        <T as Config>::OffchainSignature,
+    );
+
+    /// Context prepended to the payload of a vote signed off-chain
+    pub const SIGNED_VOTE_CONTEXT: &[u8] = b"pallet_voting::signed_vote";
+
+    /// Creates keys and signatures for the signed votes benchmark
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<Public, Signature> {
+        /// Generate a new key pair, returning its public key
+        fn create_public() -> Public;
+
+        /// Sign `message` with the key of `public`
+        fn sign(public: &Public, message: &[u8]) -> Signature;
+    }
+
+    /// Memberships and asset balances the eligibility rules of proposals are checked against
+    pub trait VoterEligibility<AccountId> {
+        /// Whether `who` is a member of the body voting on member-only proposals
+        fn is_member(who: &AccountId) -> bool;
+
+        /// Balance `who` holds of an approved asset, zero if the asset is not approved
+        fn asset_balance(asset_id: u32, who: &AccountId) -> u128;
+    }
+
+    impl<AccountId> VoterEligibility<AccountId> for () {
+        fn is_member(_who: &AccountId) -> bool {
+            false
+        }
+
+        fn asset_balance(_asset_id: u32, _who: &AccountId) -> u128 {
+            0
+        }
+    }
+
+    /// Account store of `Config::Currency` that checkpoints free balances for vote snapshots
+    ///
+    /// Wraps the account store of the runtime, usually `System`, and records the free balance of
+    /// an account before the first change to it in each block. Every balance change costs
+    /// `WeightInfo::note_balance_change`, which the runtime has to add to the weights of the
+    /// calls changing balances and to the base weight of extrinsics paying fees.
+    pub struct CheckpointedAccountStore<T, Store>(PhantomData<(T, Store)>);
+
+    impl<T, Store, Data> StoredMap<T::AccountId, Data> for CheckpointedAccountStore<T, Store>
+    where
+        T: Config,
+        Store: StoredMap<T::AccountId, Data>,
+    {
+        fn get(who: &T::AccountId) -> Data {
+            Store::get(who)
+        }
+
+        fn try_mutate_exists<R, E: From<DispatchError>>(
+            who: &T::AccountId,
+            f: impl FnOnce(&mut Option<Data>) -> Result<R, E>,
+        ) -> Result<R, E> {
+            let previous = T::Currency::free_balance(who);
+            let result = Store::try_mutate_exists(who, f)?;
+            if T::Currency::free_balance(who) != previous {
+                Pallet::<T>::note_balance_change(who, previous);
+            }
+            Ok(result)
+        }
+    }
+
+    /// The in-code storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
 
@@ -42,6 +194,18 @@ pub mod pallet {
         /// Type representing the weight of this pallet
         type WeightInfo: WeightInfo;
 
+        /// Currency whose balance weighs votes and is locked while a vote is active
+        type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>
+            + ReservableCurrency<Self::AccountId>;
+
+        /// Maximum number of vote locks a single account can hold
+        #[pallet::constant]
+        type MaxVotesPerAccount: Get<u32>;
+
+        /// Number of blocks a conviction lock period lasts after a proposal ends
+        #[pallet::constant]
+        type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+
         /// Maximum number of options per proposal
         #[pallet::constant]
         type MaxOptions: Get<u32>;
@@ -50,6 +214,10 @@ pub mod pallet {
         #[pallet::constant]
         type MaxDescriptionLength: Get<u32>;
 
+        /// Maximum length of a single proposal option
+        #[pallet::constant]
+        type MaxOptionLength: Get<u32>;
+
         /// Minimum voting period in blocks
         #[pallet::constant]
         type MinVotingPeriod: Get<BlockNumberFor<Self>>;
@@ -57,32 +225,438 @@ pub mod pallet {
         /// Maximum voting period in blocks
         #[pallet::constant]
         type MaxVotingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of blocks between creating a proposal and the start of its voting period
+        #[pallet::constant]
+        type MaxStartDelay: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of proposals whose voting period ends in the same block
+        #[pallet::constant]
+        type MaxProposalsEndingPerBlock: Get<u32>;
+
+        /// Maximum number of pending proposals whose voting period starts in the same block
+        #[pallet::constant]
+        type MaxProposalsStartingPerBlock: Get<u32>;
+
+        /// Minimum quorum (total vote weight) a proposal can require
+        #[pallet::constant]
+        type MinQuorum: Get<BalanceOf<Self>>;
+
+        /// Minimum share of the vote weight the winning option of a proposal can be required to reach
+        #[pallet::constant]
+        type MinApprovalThreshold: Get<Perbill>;
+
+        /// Scheduler used to dispatch the call of a passed proposal
+        type Scheduler: ScheduleAnon<BlockNumberFor<Self>, CallOf<Self>, PalletsOriginOf<Self>>;
+
+        /// Preimage provider used to store proposal calls that are too large to inline
+        type Preimages: QueryPreimage + StorePreimage;
+
+        /// Maximum encoded length of the call of a proposal
+        ///
+        /// Calls too large to inline are noted as preimages without a deposit, so this bounds
+        /// the storage a proposal can take up.
+        #[pallet::constant]
+        type MaxCallLength: Get<u32>;
+
+        /// Origin the call of a passed proposal is dispatched with
+        type EnactmentOrigin: Get<PalletsOriginOf<Self>>;
+
+        /// Number of blocks between a proposal passing and its call being dispatched
+        #[pallet::constant]
+        type EnactmentDelay: Get<BlockNumberFor<Self>>;
+
+        /// Minimum quorum of a proposal with a call, as a share of the total issuance
+        ///
+        /// Keeps a single account from passing a call with a quorum it meets on its own.
+        #[pallet::constant]
+        type MinEnactmentQuorum: Get<Perbill>;
+
+        /// Minimum voting period of a proposal with a call
+        #[pallet::constant]
+        type MinEnactmentPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of accounts delegating to the same account in the same scope, which is
+        /// also the maximum number of delegations counted towards a single vote
+        #[pallet::constant]
+        type MaxDelegators: Get<u32>;
+
+        /// Maximum length of a delegation chain that is followed when counting a vote
+        #[pallet::constant]
+        type MaxDelegationDepth: Get<u32>;
+
+        /// Number of blocks after `end_block` during which secret ballots can be revealed
+        #[pallet::constant]
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Deposit reserved with a secret ballot commitment and slashed if it is never revealed
+        #[pallet::constant]
+        type CommitDeposit: Get<BalanceOf<Self>>;
+
+        /// Maximum number of distinct rankings stored for a ranked-choice proposal
+        #[pallet::constant]
+        type MaxRankings: Get<u32>;
+
+        /// Memberships and asset balances checked by the eligibility rules of proposals
+        type Eligibility: VoterEligibility<Self::AccountId>;
+
+        /// Maximum number of accounts on the allowlist of a proposal
+        #[pallet::constant]
+        type MaxAllowlist: Get<u32>;
+
+        /// Signature of a vote signed off-chain
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+        /// Public key verifying a vote signed off-chain, identifying the voter
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Maximum number of signed votes submitted in one batch
+        #[pallet::constant]
+        type MaxSignedVotes: Get<u32>;
+
+        /// Origin allowed to cancel any active or pending proposal
+        type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to veto a proposal, even after it passed
+        type VetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum length of the reason given for a veto
+        #[pallet::constant]
+        type MaxVetoReasonLength: Get<u32>;
+
+        /// Maximum number of balance checkpoints kept per account and currency
+        #[pallet::constant]
+        type MaxCheckpoints: Get<u32>;
+
+        /// Maximum number of proposals kept in the voting history of an account
+        #[pallet::constant]
+        type MaxVoterHistory: Get<u32>;
+
+        /// Identifier of the pot account participation rewards are paid from
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Reward taken from slashed deposits for the voters of a proposal that reaches quorum,
+        /// zero to only pay rewards earmarked with `fund_reward`
+        #[pallet::constant]
+        type ParticipationReward: Get<BalanceOf<Self>>;
+
+        /// Share of the total issuance the votes on a proposal, other than the proposer's, must
+        /// reach for its voters to be rewarded
+        #[pallet::constant]
+        type MinRewardTurnout: Get<Perbill>;
+
+        /// Number of blocks after a proposal ends during which its rewards can be claimed
+        #[pallet::constant]
+        type RewardClaimPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of reward pools that expire in the same block
+        #[pallet::constant]
+        type MaxRewardsExpiringPerBlock: Get<u32>;
+
+        /// Amount reserved from the proposer when a proposal is created
+        #[pallet::constant]
+        type ProposalDeposit: Get<BalanceOf<Self>>;
+
+        /// Share of the quorum the turnout must reach for the deposit to be returned
+        #[pallet::constant]
+        type MinSupport: Get<Perbill>;
+
+        /// Creates keys and signatures for the signed votes benchmark
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::OffchainPublic, Self::OffchainSignature>;
+    }
+
+    /// Proposal status enumeration
+    #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ProposalStatus {
+        /// Proposal is open for voting
+        Active,
+        /// Winning option reached quorum and the approval threshold
+        Passed,
+        /// Winning option missed the approval threshold
+        Rejected,
+        /// Total vote weight stayed below the quorum
+        NoQuorum,
+        /// Several options share the highest vote weight
+        Tied,
+        /// Proposal was cancelled
+        Cancelled,
+        /// Voting period has not started yet
+        Pending,
+        /// Proposal was vetoed
+        Vetoed,
+    }
+
+    impl Default for ProposalStatus {
+        fn default() -> Self {
+            ProposalStatus::Active
+        }
+    }
+
+    /// How voters express their choice on a proposal
+    #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum VotingMode {
+        /// A single option per voter, the option with the most weight wins
+        SingleChoice,
+        /// Any subset of the options, every approved option receives the full weight
+        Approval,
+        /// Options in order of preference, decided by instant-runoff at the end
+        RankedChoice,
+    }
+
+    impl Default for VotingMode {
+        fn default() -> Self {
+            VotingMode::SingleChoice
+        }
     }
 
     /// Proposal information
-    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
-    pub struct ProposalInfo<AccountId, BlockNumber> {
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ProposalInfo<AccountId, Balance, BlockNumber, Call, Description, Options> {
         /// Proposal creator
         pub proposer: AccountId,
         /// Proposal description
-        pub description: Vec<u8>,
+        pub description: Description,
         /// Voting options
-        pub options: Vec<Vec<u8>>,
+        pub options: Options,
+        /// Call dispatched when the proposal passes
+        pub call: Option<Call>,
         /// Voting start block
         pub start_block: BlockNumber,
         /// Voting end block
         pub end_block: BlockNumber,
-        /// Whether the proposal is active
-        pub is_active: bool,
+        /// Minimum total vote weight for the proposal to be decided
+        pub quorum: Balance,
+        /// Share of the total vote weight the winning option must reach
+        pub approval_threshold: Perbill,
+        /// Proposal status
+        pub status: ProposalStatus,
+        /// Whether votes are committed during the voting period and revealed after it
+        pub secret_ballot: bool,
+        /// How voters express their choice
+        pub mode: VotingMode,
+        /// Amount reserved from the proposer until the proposal ends
+        pub deposit: Balance,
     }
 
-    /// Vote information
+    /// Parameters of a new proposal
     #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
-    pub struct VoteInfo {
-        /// Selected option index
+    pub struct ProposalParams<Balance, BlockNumber, Call, Eligibility> {
+        /// Proposal description
+        pub description: Vec<u8>,
+        /// Voting options
+        pub options: Vec<Vec<u8>>,
+        /// Number of blocks votes are accepted for
+        pub voting_period: BlockNumber,
+        /// Number of blocks before the voting period starts
+        pub start_delay: BlockNumber,
+        /// Minimum total vote weight for the proposal to be decided
+        pub quorum: Balance,
+        /// Share of the total vote weight the winning option must reach
+        pub approval_threshold: Perbill,
+        /// Call dispatched when the proposal passes
+        pub call: Option<Call>,
+        /// Whether votes are committed during the voting period and revealed after it
+        pub secret_ballot: bool,
+        /// How voters express their choice
+        pub mode: VotingMode,
+        /// Accounts allowed to vote
+        pub eligibility: Eligibility,
+        /// Number of options elected, one for a regular proposal
+        pub seats: u32,
+    }
+
+    /// Conviction of a vote, trading a longer lock for a higher vote weight
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Conviction {
+        /// 0.1x weight, unlocked as soon as the proposal ends
+        None,
+        /// 1x weight, locked for 1 period after the proposal ends
+        Locked1x,
+        /// 2x weight, locked for 2 periods after the proposal ends
+        Locked2x,
+        /// 3x weight, locked for 4 periods after the proposal ends
+        Locked3x,
+        /// 4x weight, locked for 8 periods after the proposal ends
+        Locked4x,
+        /// 5x weight, locked for 16 periods after the proposal ends
+        Locked5x,
+        /// 6x weight, locked for 32 periods after the proposal ends
+        Locked6x,
+    }
+
+    impl Default for Conviction {
+        fn default() -> Self {
+            Conviction::None
+        }
+    }
+
+    impl Conviction {
+        /// Number of `VoteLockingPeriod`s the balance stays locked after the proposal ends
+        pub fn lock_periods(self) -> u32 {
+            match self {
+                Conviction::None => 0,
+                Conviction::Locked1x => 1,
+                Conviction::Locked2x => 2,
+                Conviction::Locked3x => 4,
+                Conviction::Locked4x => 8,
+                Conviction::Locked5x => 16,
+                Conviction::Locked6x => 32,
+            }
+        }
+
+        /// Vote weight of `balance` locked with this conviction
+        pub fn votes<Balance: AtLeast32BitUnsigned>(self, balance: Balance) -> Balance {
+            match self {
+                Conviction::None => balance / 10u8.into(),
+                Conviction::Locked1x => balance,
+                Conviction::Locked2x => balance.saturating_mul(2u8.into()),
+                Conviction::Locked3x => balance.saturating_mul(3u8.into()),
+                Conviction::Locked4x => balance.saturating_mul(4u8.into()),
+                Conviction::Locked5x => balance.saturating_mul(5u8.into()),
+                Conviction::Locked6x => balance.saturating_mul(6u8.into()),
+            }
+        }
+    }
+
+    /// Vote information
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VoteInfo<Balance> {
+        /// Selected option index, the first option of the ballot in approval and ranked-choice mode
         pub option_index: u32,
-        /// Vote weight (could be balance-based in future)
-        pub weight: u32,
+        /// Balance locked by the voter
+        pub balance: Balance,
+        /// Conviction the balance was locked with
+        pub conviction: Conviction,
+        /// Vote weight, the locked balance multiplied by the conviction
+        pub weight: Balance,
+        /// Vote weight delegated to the voter and counted towards the same option
+        pub delegated: Balance,
+    }
+
+    /// Secret ballot committed during the voting period
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Commitment<Hash, Balance> {
+        /// Hash of the option index and salt
+        pub hash: Hash,
+        /// Balance locked by the voter
+        pub balance: Balance,
+        /// Conviction the balance was locked with
+        pub conviction: Conviction,
+        /// Deposit reserved until the ballot is revealed
+        pub deposit: Balance,
+    }
+
+    /// Proposals a delegation applies to
+    #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum DelegationScope {
+        /// Every proposal without a delegation scoped to it
+        All,
+        /// A single proposal
+        Proposal(u32),
+    }
+
+    impl Default for DelegationScope {
+        fn default() -> Self {
+            DelegationScope::All
+        }
+    }
+
+    /// Accounts allowed to vote on a proposal
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum EligibilityRule<Allowlist> {
+        /// Every account
+        Open,
+        /// Members according to `VoterEligibility`
+        Members,
+        /// Holders of at least `min_balance` of an approved asset
+        AssetHolder { asset_id: u32, min_balance: u128 },
+        /// Accounts on the list
+        Allowlist(Allowlist),
+    }
+
+    impl<Allowlist> Default for EligibilityRule<Allowlist> {
+        fn default() -> Self {
+            EligibilityRule::Open
+        }
+    }
+
+    /// Voting power handed by an account to another account
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Delegation<AccountId, Balance> {
+        /// Proposals the delegation applies to
+        pub scope: DelegationScope,
+        /// Account voting with the delegated power
+        pub target: AccountId,
+        /// Balance locked by the delegator
+        pub balance: Balance,
+        /// Conviction the balance was locked with
+        pub conviction: Conviction,
+    }
+
+    /// Balance locked on behalf of a single vote
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VoteLock<Balance, BlockNumber> {
+        /// Proposal the vote was cast on, `None` for the lock left behind by a revoked delegation
+        pub proposal_id: Option<u32>,
+        /// Locked amount
+        pub amount: Balance,
+        /// Last block at which the amount stays locked
+        pub locked_until: BlockNumber,
+    }
+
+    /// Maximum length of a storage cursor kept between cleanup passes
+    pub const MAX_CURSOR_LEN: u32 = 256;
+
+    /// Number of proposals on a page of an account's voting history
+    pub const VOTER_HISTORY_PAGE_SIZE: u32 = 20;
+
+    /// Per-proposal storage removed by the cleanup of an ended proposal, in removal order
+    #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum CleanupStage {
+        /// Individual votes
+        Votes,
+        /// Approval and ranked-choice ballots
+        Ballots,
+        /// Delegations counted towards votes
+        DelegatedVotes,
+        /// Tallies of the options
+        VoteResults,
+        /// Claimed participation rewards
+        RewardClaims,
+    }
+
+    /// Progress of the cleanup of an ended proposal, with the summary announced once it is done
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct CleanupTask<Balance> {
+        /// Final status of the proposal
+        pub status: ProposalStatus,
+        /// Winning option, if any
+        pub winning_option: Option<u32>,
+        /// Weight of all votes
+        pub turnout: Balance,
+        /// Number of votes removed so far
+        pub votes: u32,
+        /// Storage currently being removed
+        pub stage: CleanupStage,
+        /// Where the removal of the current stage continues
+        pub cursor: Option<BoundedVec<u8, ConstU32<MAX_CURSOR_LEN>>>,
+    }
+
+    /// Participation reward shared by the voters of an ended proposal
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct RewardPool<Balance, BlockNumber> {
+        /// Reward set aside in the pot for the voters
+        pub amount: Balance,
+        /// Reward claimed so far
+        pub claimed: Balance,
+        /// Weight of the votes other than the proposer's, each voter is paid its share of it
+        pub turnout: Balance,
+        /// Winning option, kept for the cleanup once the pool expires
+        pub winning_option: Option<u32>,
+        /// Block the unclaimed reward returns to the reserve in
+        pub expires_at: BlockNumber,
     }
 
     /// Storage for proposals
@@ -92,7 +666,7 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         u32,
-        ProposalInfo<T::AccountId, BlockNumberFor<T>>,
+        ProposalInfoOf<T>,
         OptionQuery,
     >;
 
@@ -105,7 +679,7 @@ pub mod pallet {
         u32, // proposal_id
         Blake2_128Concat,
         T::AccountId, // voter
-        VoteInfo,
+        VoteInfo<BalanceOf<T>>,
         OptionQuery,
     >;
 
@@ -118,244 +692,2178 @@ pub mod pallet {
         u32, // proposal_id
         Blake2_128Concat,
         u32, // option_index
-        u32, // vote_count
+        BalanceOf<T>, // total vote weight
         ValueQuery,
     >;
 
-    /// Next proposal ID
+    /// Storage for the vote locks held by each account
     #[pallet::storage]
-    #[pallet::getter(fn next_proposal_id)]
-    pub type NextProposalId<T> = StorageValue<_, u32, ValueQuery>;
+    #[pallet::getter(fn vote_locks)]
+    pub type VoteLocks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<VoteLock<BalanceOf<T>, BlockNumberFor<T>>, T::MaxVotesPerAccount>,
+        ValueQuery,
+    >;
 
-    /// Pallets use events to inform users when important changes are made.
-    /// https://docs.substrate.io/main-docs/build/events-errors/
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// A new proposal has been created
-        ProposalCreated {
-            proposal_id: u32,
-            proposer: T::AccountId,
-            description: Vec<u8>,
-        },
-        /// A vote has been cast
-        VoteCast {
-            proposal_id: u32,
-            voter: T::AccountId,
-            option_index: u32,
-        },
-        /// A proposal has ended
-        ProposalEnded {
-            proposal_id: u32,
-            winning_option: Option<u32>,
-        },
-        /// A proposal has been cancelled
-        ProposalCancelled {
-            proposal_id: u32,
-        },
-    }
+    /// Approved options or ranking of each voter on approval and ranked-choice proposals
+    #[pallet::storage]
+    #[pallet::getter(fn ballots)]
+    pub type Ballots<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32, // proposal_id
+        Blake2_128Concat,
+        T::AccountId, // voter
+        BoundedVec<u32, T::MaxOptions>,
+        OptionQuery,
+    >;
 
-    // Errors inform users that something went wrong.
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Proposal does not exist
-        ProposalNotFound,
-        /// Proposal is not active
-        ProposalNotActive,
-        /// Voting period has ended
-        VotingPeriodEnded,
-        /// Voting period has not started
-        VotingPeriodNotStarted,
-        /// Invalid option index
-        InvalidOptionIndex,
-        /// User has already voted
-        AlreadyVoted,
-        /// Too many options
-        TooManyOptions,
-        /// Description too long
-        DescriptionTooLong,
-        /// Invalid voting period
-        InvalidVotingPeriod,
-        /// Not the proposer
-        NotProposer,
-        /// No options provided
-        NoOptions,
-    }
+    /// Distinct rankings of a ranked-choice proposal with the total weight behind each
+    #[pallet::storage]
+    #[pallet::getter(fn ranked_ballots)]
+    pub type RankedBallots<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32, // proposal_id
+        BoundedVec<(BoundedVec<u32, T::MaxOptions>, BalanceOf<T>), T::MaxRankings>,
+        ValueQuery,
+    >;
+
+    /// Total vote weight cast on a proposal, each voter counted once
+    #[pallet::storage]
+    #[pallet::getter(fn turnout)]
+    pub type Turnout<T: Config> = StorageMap<_, Blake2_128Concat, u32, BalanceOf<T>, ValueQuery>;
+
+    /// Secret ballots that have not been revealed yet
+    #[pallet::storage]
+    #[pallet::getter(fn commitments)]
+    pub type Commitments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32, // proposal_id
+        Blake2_128Concat,
+        T::AccountId, // voter
+        Commitment<T::Hash, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Delegations made by each account
+    #[pallet::storage]
+    #[pallet::getter(fn delegations)]
+    pub type Delegations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<Delegation<T::AccountId, BalanceOf<T>>, T::MaxVotesPerAccount>,
+        ValueQuery,
+    >;
+
+    /// Accounts delegating to an account, per scope
+    #[pallet::storage]
+    #[pallet::getter(fn delegators)]
+    pub type Delegators<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // target
+        Blake2_128Concat,
+        DelegationScope,
+        BoundedVec<T::AccountId, T::MaxDelegators>,
+        ValueQuery,
+    >;
+
+    /// Delegated weight counted towards the vote of another account
+    #[pallet::storage]
+    #[pallet::getter(fn delegated_votes)]
+    pub type DelegatedVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32, // proposal_id
+        Blake2_128Concat,
+        T::AccountId, // delegator
+        (T::AccountId, BalanceOf<T>), // voter and counted weight
+        OptionQuery,
+    >;
+
+    /// Nonce the next vote signed off-chain by an account must carry
+    #[pallet::storage]
+    #[pallet::getter(fn signed_vote_nonce)]
+    pub type SignedVoteNonces<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Eligibility rule of proposals not open to every account
+    #[pallet::storage]
+    #[pallet::getter(fn eligibility_rule)]
+    pub type ProposalEligibility<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, EligibilityRuleOf<T>, OptionQuery>;
+
+    /// Number of winners of proposals electing more than one option
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_seats)]
+    pub type ProposalSeats<T: Config> = StorageMap<_, Blake2_128Concat, u32, u32, OptionQuery>;
+
+    /// Options of a proposal in the order they first received vote weight, to break ties
+    #[pallet::storage]
+    #[pallet::getter(fn first_votes)]
+    pub type FirstVotes<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BoundedVec<u32, T::MaxOptions>, ValueQuery>;
+
+    /// Scheduled enactments of passed proposals
+    #[pallet::storage]
+    #[pallet::getter(fn enactment_task)]
+    pub type EnactmentTasks<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, TaskAddressOf<T>, OptionQuery>;
+
+    /// Reasons given for vetoing proposals
+    #[pallet::storage]
+    #[pallet::getter(fn veto_reason)]
+    pub type VetoReasons<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, VetoReasonOf<T>, OptionQuery>;
+
+    /// Participation rewards of ended proposals that can still be claimed
+    #[pallet::storage]
+    #[pallet::getter(fn reward_pool)]
+    pub type RewardPools<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        RewardPool<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Reward pools indexed by the block they expire in
+    #[pallet::storage]
+    #[pallet::getter(fn rewards_expiring_at)]
+    pub type RewardsExpiringAt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>, // expires_at
+        BoundedVec<u32, T::MaxRewardsExpiringPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Rewards claimed by each voter of a proposal
+    #[pallet::storage]
+    #[pallet::getter(fn reward_claim)]
+    pub type RewardClaims<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32, // proposal_id
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Reward set aside in the pot and not claimed yet
+    #[pallet::storage]
+    #[pallet::getter(fn outstanding_rewards)]
+    pub type OutstandingRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Participation rewards earmarked for proposals that have not ended yet
+    #[pallet::storage]
+    #[pallet::getter(fn reward_funds)]
+    pub type RewardFunds<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BalanceOf<T>, ValueQuery>;
+
+    /// Slashed deposits and unpaid rewards in the pot that later reward pools are funded from
+    #[pallet::storage]
+    #[pallet::getter(fn reward_reserve)]
+    pub type RewardReserve<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Proposals each account has voted on, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn votes_by_account)]
+    pub type VotesByAccount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u32, T::MaxVoterHistory>,
+        ValueQuery,
+    >;
+
+    /// Block whose balances weigh the votes on a proposal
+    #[pallet::storage]
+    #[pallet::getter(fn snapshot_block)]
+    pub type ProposalSnapshots<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BlockNumberFor<T>, OptionQuery>;
+
+    /// Native balances of an account before the blocks they changed in
+    #[pallet::storage]
+    #[pallet::getter(fn native_checkpoints)]
+    pub type NativeCheckpoints<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        CheckpointsOf<T, BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Asset balances of an account before the blocks they changed in
+    #[pallet::storage]
+    #[pallet::getter(fn asset_checkpoints)]
+    pub type AssetCheckpoints<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32, // asset_id
+        Blake2_128Concat,
+        T::AccountId,
+        CheckpointsOf<T, u128>,
+        ValueQuery,
+    >;
+
+    /// Ended proposals whose votes are still to be removed by `on_idle`
+    #[pallet::storage]
+    #[pallet::getter(fn cleanup_queue)]
+    pub type CleanupQueue<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, CleanupTask<BalanceOf<T>>, OptionQuery>;
+
+    /// Pending proposals indexed by the block their voting period starts in
+    #[pallet::storage]
+    #[pallet::getter(fn proposals_starting_at)]
+    pub type ProposalsStartingAt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>, // start_block
+        BoundedVec<u32, T::MaxProposalsStartingPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Proposals indexed by the block their voting period ends in
+    #[pallet::storage]
+    #[pallet::getter(fn proposals_ending_at)]
+    pub type ProposalsEndingAt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>, // end_block
+        BoundedVec<u32, T::MaxProposalsEndingPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Next proposal ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_proposal_id)]
+    pub type NextProposalId<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// Pallets use events to inform users when important changes are made.
+    /// https://docs.substrate.io/main-docs/build/events-errors/
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A new proposal has been created
+        ProposalCreated {
+            proposal_id: u32,
+            proposer: T::AccountId,
+            description: Vec<u8>,
+        },
+        /// The voting period of a pending proposal has started
+        ProposalOpened { proposal_id: u32 },
+        /// A vote has been cast
+        VoteCast {
+            proposal_id: u32,
+            voter: T::AccountId,
+            option_index: u32,
+            conviction: Conviction,
+            weight: BalanceOf<T>,
+            delegated: BalanceOf<T>,
+        },
+        /// A secret ballot has been committed
+        VoteCommitted {
+            proposal_id: u32,
+            voter: T::AccountId,
+        },
+        /// An unrevealed secret ballot has been removed and its deposit slashed
+        CommitmentReaped {
+            proposal_id: u32,
+            voter: T::AccountId,
+            slashed: BalanceOf<T>,
+        },
+        /// A proposal has ended, with its winners ordered from the most to the least vote weight
+        ProposalEnded {
+            proposal_id: u32,
+            status: ProposalStatus,
+            winning_option: Option<u32>,
+            winners: Vec<u32>,
+            tallies: Vec<BalanceOf<T>>,
+        },
+        /// A proposal has been cancelled
+        ProposalCancelled {
+            proposal_id: u32,
+        },
+        /// A proposal has been vetoed
+        ProposalVetoed {
+            proposal_id: u32,
+            reason: Vec<u8>,
+        },
+        /// A participation reward has been earmarked for the voters of a proposal
+        RewardFunded {
+            proposal_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A participation reward has been set aside for the voters of a proposal
+        RewardPoolOpened {
+            proposal_id: u32,
+            amount: BalanceOf<T>,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// A voter has claimed its participation reward
+        RewardClaimed {
+            proposal_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Too many reward pools expire in the block, the proposal pays no participation reward
+        RewardPoolsFull {
+            proposal_id: u32,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// The claim period of a reward pool is over, the unclaimed reward returns to the reserve
+        RewardPoolExpired {
+            proposal_id: u32,
+            unclaimed: BalanceOf<T>,
+        },
+        /// The deposit of a proposal has been returned to its proposer
+        DepositReturned {
+            proposal_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The deposit of a proposal has been slashed into the reward pot
+        DepositSlashed {
+            proposal_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The votes of an ended proposal have been removed
+        ProposalArchived {
+            proposal_id: u32,
+            status: ProposalStatus,
+            winning_option: Option<u32>,
+            turnout: BalanceOf<T>,
+            votes: u32,
+        },
+        /// The call of a passed proposal has been scheduled for dispatch
+        ProposalEnactmentScheduled {
+            proposal_id: u32,
+            when: BlockNumberFor<T>,
+        },
+        /// The call of a passed proposal could not be scheduled
+        ProposalEnactmentFailed {
+            proposal_id: u32,
+        },
+        /// Expired vote locks have been released
+        VotesUnlocked {
+            who: T::AccountId,
+            remaining: BalanceOf<T>,
+        },
+        /// An account has delegated its voting power
+        Delegated {
+            who: T::AccountId,
+            target: T::AccountId,
+            scope: DelegationScope,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
+        },
+        /// An account has revoked a delegation
+        Undelegated {
+            who: T::AccountId,
+            scope: DelegationScope,
+        },
+    }
+
+    // Errors inform users that something went wrong.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Proposal does not exist
+        ProposalNotFound,
+        /// Proposal is not active
+        ProposalNotActive,
+        /// Voting period has ended
+        VotingPeriodEnded,
+        /// Voting period has not started
+        VotingPeriodNotStarted,
+        /// Invalid option index
+        InvalidOptionIndex,
+        /// User has already voted
+        AlreadyVoted,
+        /// Too many options
+        TooManyOptions,
+        /// Description too long
+        DescriptionTooLong,
+        /// Option too long
+        OptionTooLong,
+        /// Invalid voting period
+        InvalidVotingPeriod,
+        /// Not the proposer
+        NotProposer,
+        /// No options provided
+        NoOptions,
+        /// Vote balance is zero
+        ZeroVoteBalance,
+        /// Vote balance exceeds the free balance
+        InsufficientBalance,
+        /// Too many vote locks held by this account
+        TooManyVotes,
+        /// Too many proposals end in the same block
+        TooManyProposalsEndingAt,
+        /// Too many proposals start in the same block
+        TooManyProposalsStartingAt,
+        /// Start delay exceeds the configured maximum
+        StartDelayTooLong,
+        /// Account is not eligible to vote on the proposal
+        NotEligible,
+        /// Too many signed votes in one batch
+        TooManySignedVotes,
+        /// Nonce of a signed vote is not the next nonce of the voter
+        InvalidNonce,
+        /// Signature of a signed vote does not match the voter
+        InvalidSignature,
+        /// Vote balance exceeds the balance held at the proposal's snapshot block
+        InsufficientSnapshotBalance,
+        /// Veto reason is too long
+        VetoReasonTooLong,
+        /// Proposal has ended without a call waiting for enactment and cannot be vetoed
+        CannotVeto,
+        /// Call of the proposal has already been dispatched
+        AlreadyEnacted,
+        /// Proposal has no participation reward to claim
+        NoReward,
+        /// Account did not vote on the proposal
+        NotVoted,
+        /// Participation reward has already been claimed
+        RewardAlreadyClaimed,
+        /// Quorum is below the configured minimum
+        QuorumTooLow,
+        /// Approval threshold is below the configured minimum
+        ApprovalThresholdTooLow,
+        /// Account cannot delegate to itself
+        SelfDelegation,
+        /// Account already delegates in this scope
+        AlreadyDelegating,
+        /// Account does not delegate in this scope
+        NotDelegating,
+        /// Delegation would create a cycle
+        DelegationCycle,
+        /// Too many accounts delegate to the target in this scope
+        TooManyDelegators,
+        /// Too many delegations made by this account
+        TooManyDelegations,
+        /// Proposal uses secret ballots, votes must be committed and revealed
+        SecretBallot,
+        /// Proposal does not use secret ballots
+        NotSecretBallot,
+        /// No secret ballot committed by this account
+        CommitmentNotFound,
+        /// Revealed option and salt do not match the commitment
+        InvalidReveal,
+        /// Reveal window has not started yet
+        RevealPeriodNotStarted,
+        /// Reveal window has ended
+        RevealPeriodEnded,
+        /// Extrinsic does not match the voting mode of the proposal
+        WrongVotingMode,
+        /// Secret ballots are only supported in single-choice mode
+        SecretBallotNotSupported,
+        /// Ballot does not contain any option
+        EmptyBallot,
+        /// Ballot contains the same option more than once
+        DuplicateOption,
+        /// Too many distinct rankings on this proposal
+        TooManyRankings,
+        /// Seats must be between one and the number of options
+        InvalidSeats,
+        /// Ranked-choice proposals elect a single option
+        SeatsNotSupported,
+        /// Reveal window has not ended yet
+        RevealPeriodNotOver,
+        /// Encoded call is longer than `MaxCallLength`
+        CallTooLong,
+        /// Not every seat of a single-choice election can reach the approval threshold
+        ThresholdUnreachable,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Finalize the proposals whose voting period ended in the previous block
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let opening = ProposalsStartingAt::<T>::take(n);
+            for proposal_id in opening.iter() {
+                Self::open_proposal(*proposal_id);
+            }
+
+            let expiring = RewardsExpiringAt::<T>::take(n);
+            for proposal_id in expiring.iter() {
+                Self::expire_reward_pool(*proposal_id);
+            }
+
+            let due = ProposalsEndingAt::<T>::take(n.saturating_sub(One::one()));
+            let mut weight = T::WeightInfo::open_proposals(opening.len() as u32)
+                .saturating_add(T::WeightInfo::expire_reward_pools(expiring.len() as u32))
+                .saturating_add(T::WeightInfo::on_initialize(due.len() as u32));
+            for proposal_id in due.iter() {
+                if let Some(proposal) = Self::proposals(proposal_id) {
+                    if proposal.mode == VotingMode::RankedChoice {
+                        weight = weight.saturating_add(T::WeightInfo::instant_runoff(
+                            proposal.options.len() as u32,
+                            Self::ranked_ballots(proposal_id).len() as u32,
+                        ));
+                    }
+                }
+                // Proposals already ended through `end_proposal` are skipped
+                let _ = Self::finalize_proposal(*proposal_id);
+            }
+            weight
+        }
+
+        /// Remove the votes of ended proposals with the weight left in the block
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::cleanup(remaining_weight)
+        }
+    }
+
+    // Dispatchable functions allow users to interact with the pallet and invoke state changes.
+    // These functions materialize as "extrinsics", which are often compared to transactions.
+    // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new proposal
+        ///
+        /// A proposal with more than one seat elects the `seats` options with the most vote
+        /// weight instead of a single winning option.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_proposal(
+            T::MaxAllowlist::get(),
+            params.call.as_ref().map_or(0, |call| call.encoded_size() as u32),
+        ))]
+        pub fn create_proposal(origin: OriginFor<T>, params: ProposalParamsOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ProposalParams {
+                description,
+                options,
+                voting_period,
+                start_delay,
+                quorum,
+                approval_threshold,
+                call,
+                secret_ballot,
+                mode,
+                eligibility,
+                seats,
+            } = params;
+
+            // Validate inputs
+            ensure!(!options.is_empty(), Error::<T>::NoOptions);
+            let options: OptionsOf<T> = options
+                .into_iter()
+                .map(|option| option.try_into())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| Error::<T>::OptionTooLong)?
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyOptions)?;
+            let description: DescriptionOf<T> =
+                description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+            ensure!(
+                voting_period >= T::MinVotingPeriod::get() &&
+                voting_period <= T::MaxVotingPeriod::get(),
+                Error::<T>::InvalidVotingPeriod
+            );
+            ensure!(start_delay <= T::MaxStartDelay::get(), Error::<T>::StartDelayTooLong);
+            ensure!(quorum >= T::MinQuorum::get(), Error::<T>::QuorumTooLow);
+            ensure!(
+                approval_threshold >= T::MinApprovalThreshold::get(),
+                Error::<T>::ApprovalThresholdTooLow
+            );
+            ensure!(
+                !secret_ballot || mode == VotingMode::SingleChoice,
+                Error::<T>::SecretBallotNotSupported
+            );
+            ensure!(
+                seats >= 1 && seats as usize <= options.len(),
+                Error::<T>::InvalidSeats
+            );
+            ensure!(
+                seats == 1 || mode != VotingMode::RankedChoice,
+                Error::<T>::SeatsNotSupported
+            );
+            // Each voter backs a single option, so the seats share at most the whole turnout
+            ensure!(
+                mode != VotingMode::SingleChoice ||
+                    approval_threshold <= Perbill::from_rational(1, seats),
+                Error::<T>::ThresholdUnreachable
+            );
+
+            if let Some(call) = call.as_ref() {
+                ensure!(
+                    call.encoded_size() as u32 <= T::MaxCallLength::get(),
+                    Error::<T>::CallTooLong
+                );
+                ensure!(
+                    voting_period >= T::MinEnactmentPeriod::get(),
+                    Error::<T>::InvalidVotingPeriod
+                );
+                ensure!(quorum >= Self::enactment_quorum(), Error::<T>::QuorumTooLow);
+            }
+
+            let call = call.map(|call| T::Preimages::bound(*call)).transpose()?;
+
+            let proposal_id = Self::next_proposal_id();
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let start_block = current_block.saturating_add(start_delay);
+            let end_block = start_block.saturating_add(voting_period);
+            let status = if start_delay.is_zero() {
+                ProposalStatus::Active
+            } else {
+                ProposalStatus::Pending
+            };
+
+            let proposal = ProposalInfo {
+                proposer: who.clone(),
+                description: description.clone(),
+                options,
+                call,
+                start_block,
+                end_block,
+                quorum,
+                approval_threshold,
+                status,
+                secret_ballot,
+                mode,
+                deposit: T::ProposalDeposit::get(),
+            };
+
+            ensure!(
+                T::Currency::can_reserve(&who, proposal.deposit),
+                Error::<T>::InsufficientBalance
+            );
+            if status == ProposalStatus::Pending {
+                ensure!(
+                    Self::proposals_starting_at(&start_block).len() <
+                        T::MaxProposalsStartingPerBlock::get() as usize,
+                    Error::<T>::TooManyProposalsStartingAt
+                );
+            }
+            ProposalsEndingAt::<T>::try_mutate(&Self::closing_block(&proposal), |ids| {
+                ids.try_push(proposal_id)
+            })
+                .map_err(|_| Error::<T>::TooManyProposalsEndingAt)?;
+            if status == ProposalStatus::Pending {
+                ProposalsStartingAt::<T>::try_mutate(&start_block, |ids| ids.try_push(proposal_id))
+                    .map_err(|_| Error::<T>::TooManyProposalsStartingAt)?;
+            }
+            T::Currency::reserve(&who, proposal.deposit)?;
+            Proposals::<T>::insert(&proposal_id, &proposal);
+            ProposalSnapshots::<T>::insert(&proposal_id, current_block);
+            if eligibility != EligibilityRule::Open {
+                ProposalEligibility::<T>::insert(&proposal_id, eligibility);
+            }
+            if seats > 1 {
+                ProposalSeats::<T>::insert(&proposal_id, seats);
+            }
+            NextProposalId::<T>::put(proposal_id.saturating_add(1));
+
+            Self::deposit_event(Event::ProposalCreated {
+                proposal_id,
+                proposer: who,
+                description: description.into_inner(),
+            });
+
+            Ok(())
+        }
+
+        /// Cast a vote on a proposal
+        ///
+        /// Voting power delegated to the voter, directly or through a chain of at most
+        /// `MaxDelegationDepth` delegations, is counted towards the same option. A direct vote
+        /// overrides the delegation of the voter for this proposal.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::vote(T::MaxDelegators::get()))]
+        pub fn vote(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            option_index: u32,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(proposal.mode == VotingMode::SingleChoice, Error::<T>::WrongVotingMode);
+            let ballot = Self::validate_vote(&who, proposal_id, &proposal, sp_std::vec![option_index], balance)?;
+
+            Self::lock_vote(&who, proposal_id, &proposal, balance, conviction)?;
+            let delegations = Self::count_vote(who, proposal_id, &proposal, ballot, balance, conviction)?;
+
+            Ok(Some(T::WeightInfo::vote(delegations)).into())
+        }
+
+        /// End a proposal (can be called by anyone after voting period)
+        ///
+        /// Proposals are finalized automatically at the start of the block after `end_block`,
+        /// so this is only needed if that did not happen.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::end_proposal().saturating_add(
+            T::WeightInfo::instant_runoff(T::MaxOptions::get(), T::MaxRankings::get())
+        ))]
+        pub fn end_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            Self::finalize_proposal(proposal_id)
+        }
+
+        /// Cancel a proposal (by the proposer or `CancelOrigin`)
+        ///
+        /// The deposit is returned to a proposer cancelling its own proposal, and slashed when the
+        /// proposal is cancelled by `CancelOrigin`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_proposal())]
+        pub fn cancel_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+        ) -> DispatchResult {
+            let who = match T::CancelOrigin::try_origin(origin) {
+                Ok(_) => None,
+                Err(origin) => Some(ensure_signed(origin)?),
+            };
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                matches!(proposal.status, ProposalStatus::Active | ProposalStatus::Pending),
+                Error::<T>::ProposalNotActive
+            );
+            match who {
+                Some(who) => {
+                    ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+                    Self::release_deposit(proposal_id, &proposal);
+                },
+                None => Self::slash_deposit(proposal_id, &proposal),
+            }
+
+            Self::stop_proposal(proposal_id, proposal, ProposalStatus::Cancelled);
+
+            Self::deposit_event(Event::ProposalCancelled {
+                proposal_id,
+            });
+
+            Ok(())
+        }
+
+        /// Release the vote locks of `target` that have expired (can be called by anyone)
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::unlock())]
+        pub fn unlock(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let mut locks = Self::vote_locks(&target);
+            locks.retain(|lock| lock.locked_until >= current_block);
+
+            // Whatever is still locked is covered by the largest remaining lock or delegation
+            let remaining = locks
+                .iter()
+                .map(|lock| lock.amount)
+                .chain(Self::delegations(&target).iter().map(|delegation| delegation.balance))
+                .max()
+                .unwrap_or_else(Zero::zero);
+
+            if remaining.is_zero() {
+                T::Currency::remove_lock(VOTING_ID, &target);
+                VoteLocks::<T>::remove(&target);
+            } else {
+                T::Currency::set_lock(
+                    VOTING_ID,
+                    &target,
+                    remaining,
+                    WithdrawReasons::except(WithdrawReasons::RESERVE),
+                );
+                VoteLocks::<T>::insert(&target, locks);
+            }
+
+            Self::deposit_event(Event::VotesUnlocked {
+                who: target,
+                remaining,
+            });
+
+            Ok(())
+        }
+
+        /// Delegate voting power to `to` for the proposals covered by `scope`
+        ///
+        /// The balance is locked like a vote. Delegations are counted when the delegate, or the
+        /// first account down the delegation chain that votes, casts its vote.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            scope: DelegationScope,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(who != to, Error::<T>::SelfDelegation);
+            ensure!(Self::delegation_of(&who, scope).is_none(), Error::<T>::AlreadyDelegating);
+            ensure!(!balance.is_zero(), Error::<T>::ZeroVoteBalance);
+            ensure!(
+                balance <= T::Currency::free_balance(&who),
+                Error::<T>::InsufficientBalance
+            );
+
+            // Follow the chain the power would flow through, refusing to close a loop
+            let mut next = Some(to.clone());
+            for _ in 0..T::MaxDelegationDepth::get() {
+                let account = match next {
+                    Some(account) => account,
+                    None => break,
+                };
+                ensure!(account != who, Error::<T>::DelegationCycle);
+                next = Self::delegation_of(&account, scope)
+                    .or_else(|| Self::delegation_of(&account, DelegationScope::All))
+                    .map(|delegation| delegation.target);
+            }
+
+            Delegators::<T>::try_mutate(&to, scope, |delegators| delegators.try_push(who.clone()))
+                .map_err(|_| Error::<T>::TooManyDelegators)?;
+            Delegations::<T>::try_mutate(&who, |delegations| {
+                delegations.try_push(Delegation { scope, target: to.clone(), balance, conviction })
+            })
+            .map_err(|_| Error::<T>::TooManyDelegations)?;
+            T::Currency::extend_lock(
+                VOTING_ID,
+                &who,
+                balance,
+                WithdrawReasons::except(WithdrawReasons::RESERVE),
+            );
+
+            Self::deposit_event(Event::Delegated {
+                who,
+                target: to,
+                scope,
+                balance,
+                conviction,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke the delegation of the caller in `scope`
+        ///
+        /// Power already counted towards a vote stays counted, so the balance remains locked
+        /// for `MaxVotingPeriod` and `RevealPeriod` plus the conviction lock periods.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::undelegate())]
+        pub fn undelegate(
+            origin: OriginFor<T>,
+            scope: DelegationScope,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut delegations = Self::delegations(&who);
+            let index = delegations
+                .iter()
+                .position(|delegation| delegation.scope == scope)
+                .ok_or(Error::<T>::NotDelegating)?;
+            let delegation = delegations.remove(index);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let lock_duration = T::VoteLockingPeriod::get()
+                .saturating_mul(delegation.conviction.lock_periods().into());
+            let mut locks = Self::vote_locks(&who);
+            locks
+                .try_push(VoteLock {
+                    proposal_id: None,
+                    amount: delegation.balance,
+                    locked_until: current_block
+                        .saturating_add(T::MaxVotingPeriod::get())
+                        .saturating_add(T::RevealPeriod::get())
+                        .saturating_add(lock_duration),
+                })
+                .map_err(|_| Error::<T>::TooManyVotes)?;
+            VoteLocks::<T>::insert(&who, locks);
+
+            Delegators::<T>::mutate(&delegation.target, scope, |delegators| {
+                delegators.retain(|delegator| *delegator != who)
+            });
+            if delegations.is_empty() {
+                Delegations::<T>::remove(&who);
+            } else {
+                Delegations::<T>::insert(&who, delegations);
+            }
+
+            Self::deposit_event(Event::Undelegated {
+                who,
+                scope,
+            });
+
+            Ok(())
+        }
+
+        /// Commit a secret ballot on a proposal
+        ///
+        /// `hash` is the hash of the SCALE encoded `(option_index, salt)`. The balance is locked
+        /// and `CommitDeposit` reserved now, the option is only counted once revealed.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::commit_vote())]
+        pub fn commit_vote(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            hash: T::Hash,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(proposal.status != ProposalStatus::Pending, Error::<T>::VotingPeriodNotStarted);
+            ensure!(proposal.status == ProposalStatus::Active, Error::<T>::ProposalNotActive);
+            ensure!(proposal.secret_ballot, Error::<T>::NotSecretBallot);
+            ensure!(Self::is_eligible(&who, proposal_id), Error::<T>::NotEligible);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block >= proposal.start_block, Error::<T>::VotingPeriodNotStarted);
+            ensure!(current_block <= proposal.end_block, Error::<T>::VotingPeriodEnded);
+
+            ensure!(
+                !Commitments::<T>::contains_key(&proposal_id, &who) &&
+                    !Votes::<T>::contains_key(&proposal_id, &who),
+                Error::<T>::AlreadyVoted
+            );
+
+            ensure!(!balance.is_zero(), Error::<T>::ZeroVoteBalance);
+            ensure!(
+                balance <= T::Currency::free_balance(&who),
+                Error::<T>::InsufficientBalance
+            );
+            ensure!(
+                balance <= Self::snapshot_balance(&who, proposal_id),
+                Error::<T>::InsufficientSnapshotBalance
+            );
+
+            let deposit = T::CommitDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+            Self::lock_vote(&who, proposal_id, &proposal, balance, conviction)?;
+
+            Commitments::<T>::insert(
+                &proposal_id,
+                &who,
+                Commitment { hash, balance, conviction, deposit },
+            );
+
+            Self::deposit_event(Event::VoteCommitted {
+                proposal_id,
+                voter: who,
+            });
+
+            Ok(())
+        }
+
+        /// Reveal a secret ballot during the reveal window after `end_block`
+        ///
+        /// Returns the commit deposit and counts the vote as if it was cast now.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::reveal_vote(T::MaxDelegators::get()))]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            option_index: u32,
+            salt: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(proposal.status == ProposalStatus::Active, Error::<T>::ProposalNotActive);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block > proposal.end_block, Error::<T>::RevealPeriodNotStarted);
+            ensure!(
+                current_block <= Self::closing_block(&proposal),
+                Error::<T>::RevealPeriodEnded
+            );
+
+            let commitment =
+                Self::commitments(&proposal_id, &who).ok_or(Error::<T>::CommitmentNotFound)?;
+            ensure!(
+                T::Hashing::hash_of(&(option_index, salt)) == commitment.hash,
+                Error::<T>::InvalidReveal
+            );
+            ensure!(
+                option_index < proposal.options.len() as u32,
+                Error::<T>::InvalidOptionIndex
+            );
+
+            Commitments::<T>::remove(&proposal_id, &who);
+            T::Currency::unreserve(&who, commitment.deposit);
+            let ballot = BoundedVec::truncate_from(sp_std::vec![option_index]);
+            let delegations = Self::count_vote(
+                who,
+                proposal_id,
+                &proposal,
+                ballot,
+                commitment.balance,
+                commitment.conviction,
+            )?;
+
+            Ok(Some(T::WeightInfo::reveal_vote(delegations)).into())
+        }
+
+        /// Remove a secret ballot that was not revealed in time and slash its deposit
+        ///
+        /// Can be called by anyone once the reveal window is over. The deposit is returned
+        /// instead if the proposal was cancelled or vetoed.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::reap_commitment())]
+        pub fn reap_commitment(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            voter: T::AccountId,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            let commitment =
+                Self::commitments(&proposal_id, &voter).ok_or(Error::<T>::CommitmentNotFound)?;
+
+            let slashed = if matches!(
+                proposal.status,
+                ProposalStatus::Cancelled | ProposalStatus::Vetoed
+            ) {
+                T::Currency::unreserve(&voter, commitment.deposit);
+                Zero::zero()
+            } else {
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                ensure!(
+                    current_block > Self::closing_block(&proposal),
+                    Error::<T>::RevealPeriodNotOver
+                );
+                let (imbalance, _) = T::Currency::slash_reserved(&voter, commitment.deposit);
+                imbalance.peek()
+            };
+            Commitments::<T>::remove(&proposal_id, &voter);
+
+            Self::deposit_event(Event::CommitmentReaped {
+                proposal_id,
+                voter,
+                slashed,
+            });
+
+            Ok(())
+        }
+
+        /// Approve any subset of the options of an approval proposal
+        ///
+        /// Every approved option receives the full vote weight.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::vote_approval(T::MaxOptions::get(), T::MaxDelegators::get()))]
+        pub fn vote_approval(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            approvals: Vec<u32>,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(proposal.mode == VotingMode::Approval, Error::<T>::WrongVotingMode);
+            let ballot = Self::validate_vote(&who, proposal_id, &proposal, approvals, balance)?;
+            let options = ballot.len() as u32;
+
+            Self::lock_vote(&who, proposal_id, &proposal, balance, conviction)?;
+            let delegations = Self::count_vote(who, proposal_id, &proposal, ballot, balance, conviction)?;
+
+            Ok(Some(T::WeightInfo::vote_approval(options, delegations)).into())
+        }
+
+        /// Rank options of a ranked-choice proposal, most preferred first
+        ///
+        /// Options left out are never preferred. The weight counts for the first option that
+        /// has not been eliminated when the instant-runoff tally runs at the end.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::vote_ranked(T::MaxOptions::get(), T::MaxDelegators::get()))]
+        pub fn vote_ranked(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            ranking: Vec<u32>,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(proposal.mode == VotingMode::RankedChoice, Error::<T>::WrongVotingMode);
+            let ballot = Self::validate_vote(&who, proposal_id, &proposal, ranking, balance)?;
+            let options = ballot.len() as u32;
+
+            Self::lock_vote(&who, proposal_id, &proposal, balance, conviction)?;
+            let delegations = Self::count_vote(who, proposal_id, &proposal, ballot, balance, conviction)?;
+
+            Ok(Some(T::WeightInfo::vote_ranked(options, delegations)).into())
+        }
+
+        /// Submit single-choice votes signed off-chain, paying the fee on behalf of the voters
+        ///
+        /// Each voter signs the SCALE encoded `(SIGNED_VOTE_CONTEXT, genesis_hash, proposal_id,
+        /// option_index, balance, conviction, nonce)` with the nonce from `signed_vote_nonce`,
+        /// so the relayer cannot change how much is locked or for how long. The batch fails as a
+        /// whole if any vote is invalid.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::submit_signed_votes(
+            votes.len() as u32,
+            T::MaxDelegators::get(),
+        ))]
+        pub fn submit_signed_votes(
+            origin: OriginFor<T>,
+            votes: Vec<SignedVoteOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let _relayer = ensure_signed(origin)?;
+            ensure!(
+                votes.len() <= T::MaxSignedVotes::get() as usize,
+                Error::<T>::TooManySignedVotes
+            );
+
+            let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+            let count = votes.len() as u32;
+            let mut max_delegations = 0;
+            for (voter, proposal_id, option_index, balance, conviction, nonce, signature) in votes {
+                ensure!(nonce == Self::signed_vote_nonce(&voter), Error::<T>::InvalidNonce);
+                let payload = (
+                    SIGNED_VOTE_CONTEXT,
+                    genesis_hash,
+                    proposal_id,
+                    option_index,
+                    balance,
+                    conviction,
+                    nonce,
+                )
+                    .encode();
+                ensure!(signature.verify(&payload[..], &voter), Error::<T>::InvalidSignature);
+
+                let proposal =
+                    Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+                ensure!(proposal.mode == VotingMode::SingleChoice, Error::<T>::WrongVotingMode);
+                let ballot = Self::validate_vote(
+                    &voter,
+                    proposal_id,
+                    &proposal,
+                    sp_std::vec![option_index],
+                    balance,
+                )?;
+
+                SignedVoteNonces::<T>::insert(&voter, nonce.saturating_add(1));
+                Self::lock_vote(&voter, proposal_id, &proposal, balance, conviction)?;
+                let delegations =
+                    Self::count_vote(voter, proposal_id, &proposal, ballot, balance, conviction)?;
+                max_delegations = max_delegations.max(delegations);
+            }
+
+            Ok(Some(T::WeightInfo::submit_signed_votes(count, max_delegations)).into())
+        }
+
+        /// Veto a proposal (only by `VetoOrigin`)
+        ///
+        /// Active and pending proposals are stopped like cancelled ones. A passed proposal can be
+        /// vetoed until its call is dispatched, which cancels the scheduled enactment. The deposit
+        /// of an active or pending proposal is slashed.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::veto_proposal())]
+        pub fn veto_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::VetoOrigin::ensure_origin(origin)?;
+            let reason: VetoReasonOf<T> =
+                reason.try_into().map_err(|_| Error::<T>::VetoReasonTooLong)?;
+
+            let mut proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            match proposal.status {
+                ProposalStatus::Active | ProposalStatus::Pending => {
+                    Self::slash_deposit(proposal_id, &proposal);
+                    Self::stop_proposal(proposal_id, proposal, ProposalStatus::Vetoed);
+                },
+                ProposalStatus::Passed => {
+                    // Only a call waiting for its enactment can still be stopped
+                    let address =
+                        Self::enactment_task(&proposal_id).ok_or(Error::<T>::CannotVeto)?;
+                    T::Scheduler::cancel(address).map_err(|_| Error::<T>::AlreadyEnacted)?;
+                    EnactmentTasks::<T>::remove(&proposal_id);
+                    proposal.status = ProposalStatus::Vetoed;
+                    Proposals::<T>::insert(&proposal_id, &proposal);
+                    CleanupQueue::<T>::mutate(&proposal_id, |task| {
+                        if let Some(task) = task {
+                            task.status = ProposalStatus::Vetoed;
+                        }
+                    });
+                },
+                _ => return Err(Error::<T>::CannotVeto.into()),
+            }
+            VetoReasons::<T>::insert(&proposal_id, &reason);
+
+            Self::deposit_event(Event::ProposalVetoed {
+                proposal_id,
+                reason: reason.into_inner(),
+            });
 
-    // Dispatchable functions allow users to interact with the pallet and invoke state changes.
-    // These functions materialize as "extrinsics", which are often compared to transactions.
-    // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Create a new proposal
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::create_proposal())]
-        pub fn create_proposal(
+            Ok(())
+        }
+
+        /// Claim the participation reward for a vote on an ended proposal
+        ///
+        /// The reward of a proposal that reached quorum is shared pro rata to the weight of the
+        /// votes, including the weight delegated to them, and can be claimed for
+        /// `RewardClaimPeriod` blocks. The proposer is not rewarded for voting on its own proposal.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::claim_reward())]
+        pub fn claim_reward(
             origin: OriginFor<T>,
-            description: Vec<u8>,
-            options: Vec<Vec<u8>>,
-            voting_period: BlockNumberFor<T>,
+            proposal_id: u32,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            // Validate inputs
-            ensure!(!options.is_empty(), Error::<T>::NoOptions);
+            let mut pool = Self::reward_pool(&proposal_id).ok_or(Error::<T>::NoReward)?;
             ensure!(
-                options.len() <= T::MaxOptions::get() as usize,
-                Error::<T>::TooManyOptions
-            );
-            ensure!(
-                description.len() <= T::MaxDescriptionLength::get() as usize,
-                Error::<T>::DescriptionTooLong
+                !RewardClaims::<T>::contains_key(&proposal_id, &who),
+                Error::<T>::RewardAlreadyClaimed
             );
+            let vote = Self::votes(&proposal_id, &who).ok_or(Error::<T>::NotVoted)?;
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(who != proposal.proposer, Error::<T>::NoReward);
+
+            let share =
+                Perbill::from_rational(vote.weight.saturating_add(vote.delegated), pool.turnout);
+            let amount =
+                share.mul_floor(pool.amount).min(pool.amount.saturating_sub(pool.claimed));
+            ensure!(!amount.is_zero(), Error::<T>::NoReward);
+            T::Currency::transfer(
+                &Self::reward_pot(),
+                &who,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            pool.claimed = pool.claimed.saturating_add(amount);
+            RewardPools::<T>::insert(&proposal_id, &pool);
+            RewardClaims::<T>::insert(&proposal_id, &who, amount);
+            OutstandingRewards::<T>::mutate(|outstanding| {
+                *outstanding = outstanding.saturating_sub(amount)
+            });
+
+            Self::deposit_event(Event::RewardClaimed {
+                proposal_id,
+                who,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Earmark a participation reward for the voters of a proposal that has not ended yet
+        ///
+        /// The amount is transferred into the reward pot and paid with the proposal's reward pool.
+        /// If the proposal pays no reward, it joins the reserve later reward pools are funded from.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::fund_reward())]
+        pub fn fund_reward(
+            origin: OriginFor<T>,
+            proposal_id: u32,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
             ensure!(
-                voting_period >= T::MinVotingPeriod::get() &&
-                voting_period <= T::MaxVotingPeriod::get(),
-                Error::<T>::InvalidVotingPeriod
+                matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active),
+                Error::<T>::ProposalNotActive
             );
+            T::Currency::transfer(
+                &who,
+                &Self::reward_pot(),
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            RewardFunds::<T>::mutate(&proposal_id, |funds| *funds = funds.saturating_add(amount));
+
+            Self::deposit_event(Event::RewardFunded { proposal_id, who, amount });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Stop an active or pending proposal before its voting period ends
+        fn stop_proposal(proposal_id: u32, mut proposal: ProposalInfoOf<T>, status: ProposalStatus) {
+            if proposal.status == ProposalStatus::Pending {
+                ProposalsStartingAt::<T>::mutate(&proposal.start_block, |ids| {
+                    ids.retain(|id| *id != proposal_id)
+                });
+            }
+            proposal.status = status;
+            Proposals::<T>::insert(&proposal_id, &proposal);
+            ProposalsEndingAt::<T>::mutate(&Self::closing_block(&proposal), |ids| {
+                ids.retain(|id| *id != proposal_id)
+            });
+            Self::release_reward_funds(proposal_id);
+            if let Some(call) = &proposal.call {
+                T::Preimages::drop(call);
+            }
+            Self::queue_cleanup(proposal_id, status, None);
+        }
+
+        /// Start the voting period of a pending proposal
+        fn open_proposal(proposal_id: u32) {
+            Proposals::<T>::mutate(&proposal_id, |proposal| {
+                if let Some(proposal) = proposal {
+                    if proposal.status == ProposalStatus::Pending {
+                        proposal.status = ProposalStatus::Active;
+                        Self::deposit_event(Event::ProposalOpened { proposal_id });
+                    }
+                }
+            });
+        }
+
+        /// Close a proposal whose voting period is over and announce its outcome
+        pub(crate) fn finalize_proposal(proposal_id: u32) -> DispatchResult {
+            let mut proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(proposal.status == ProposalStatus::Active, Error::<T>::ProposalNotActive);
 
-            let proposal_id = Self::next_proposal_id();
             let current_block = <frame_system::Pallet<T>>::block_number();
-            let end_block = current_block.saturating_add(voting_period);
+            ensure!(current_block > Self::closing_block(&proposal), Error::<T>::VotingPeriodEnded);
 
-            let proposal = ProposalInfo {
-                proposer: who.clone(),
-                description: description.clone(),
-                options,
-                start_block: current_block,
-                end_block,
-                is_active: true,
+            let tallies = match proposal.mode {
+                VotingMode::RankedChoice =>
+                    Self::instant_runoff(proposal_id, proposal.options.len() as u32),
+                VotingMode::SingleChoice | VotingMode::Approval =>
+                    Self::get_vote_results(proposal_id),
             };
+            let turnout = Self::turnout(proposal_id);
+            let (status, winners) = match Self::proposal_seats(proposal_id) {
+                Some(seats) => Self::elect(proposal_id, &tallies, turnout, &proposal, seats),
+                None => {
+                    let (status, winning_option) = Self::tally(&tallies, turnout, &proposal);
+                    (status, winning_option.into_iter().collect())
+                },
+            };
+            let winning_option = winners.first().copied();
+            if turnout >= T::MinSupport::get().mul_ceil(proposal.quorum) {
+                Self::release_deposit(proposal_id, &proposal);
+            } else {
+                Self::slash_deposit(proposal_id, &proposal);
+            }
 
+            proposal.status = status;
             Proposals::<T>::insert(&proposal_id, &proposal);
-            NextProposalId::<T>::put(proposal_id.saturating_add(1));
+            // Votes are kept until the rewards of a proposal that reached quorum expire
+            if !Self::open_reward_pool(proposal_id, &proposal, winning_option) {
+                Self::queue_cleanup(proposal_id, status, winning_option);
+            }
 
-            Self::deposit_event(Event::ProposalCreated {
+            Self::deposit_event(Event::ProposalEnded {
                 proposal_id,
-                proposer: who,
-                description,
+                status,
+                winning_option,
+                winners,
+                tallies,
             });
 
+            if let Some(call) = proposal.call {
+                if status == ProposalStatus::Passed {
+                    Self::schedule_enactment(proposal_id, call);
+                } else {
+                    T::Preimages::drop(&call);
+                }
+            }
+
             Ok(())
         }
 
-        /// Cast a vote on a proposal
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::vote())]
-        pub fn vote(
-            origin: OriginFor<T>,
+        /// Unreserve the deposit of a proposal
+        fn release_deposit(proposal_id: u32, proposal: &ProposalInfoOf<T>) {
+            if proposal.deposit.is_zero() {
+                return
+            }
+            let unreserved = proposal
+                .deposit
+                .saturating_sub(T::Currency::unreserve(&proposal.proposer, proposal.deposit));
+            Self::deposit_event(Event::DepositReturned {
+                proposal_id,
+                who: proposal.proposer.clone(),
+                amount: unreserved,
+            });
+        }
+
+        /// Move the deposit of a proposal into the reward pot
+        fn slash_deposit(proposal_id: u32, proposal: &ProposalInfoOf<T>) {
+            if proposal.deposit.is_zero() {
+                return
+            }
+            let (slashed, _) = T::Currency::slash_reserved(&proposal.proposer, proposal.deposit);
+            let amount = slashed.peek();
+            T::Currency::resolve_creating(&Self::reward_pot(), slashed);
+            RewardReserve::<T>::mutate(|reserve| *reserve = reserve.saturating_add(amount));
+            Self::deposit_event(Event::DepositSlashed {
+                proposal_id,
+                who: proposal.proposer.clone(),
+                amount,
+            });
+        }
+
+        /// Account of the pot participation rewards are paid from
+        pub fn reward_pot() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Move the rewards earmarked for a proposal into the reserve
+        fn release_reward_funds(proposal_id: u32) -> BalanceOf<T> {
+            let funds = RewardFunds::<T>::take(proposal_id);
+            RewardReserve::<T>::mutate(|reserve| *reserve = reserve.saturating_add(funds));
+            funds
+        }
+
+        /// Set aside the participation reward of a proposal that reached quorum
+        ///
+        /// The pool pays the rewards earmarked for the proposal and up to `ParticipationReward`
+        /// from the reserve. The proposer's votes are neither rewarded nor counted towards
+        /// `MinRewardTurnout`. Returns whether a reward pool was opened, which is not the case
+        /// when there is nothing to pay, the other voters fall short of `MinRewardTurnout` or too
+        /// many pools expire in the same block.
+        fn open_reward_pool(
             proposal_id: u32,
-            option_index: u32,
+            proposal: &ProposalInfoOf<T>,
+            winning_option: Option<u32>,
+        ) -> bool {
+            // Unpaid earmarks stay in the reserve for later pools
+            let funds = Self::release_reward_funds(proposal_id);
+            if proposal.status == ProposalStatus::NoQuorum {
+                return false
+            }
+            let proposer_weight = Self::votes(proposal_id, &proposal.proposer)
+                .map_or(Zero::zero(), |vote| vote.weight.saturating_add(vote.delegated));
+            let turnout = Self::turnout(proposal_id).saturating_sub(proposer_weight);
+            let min_turnout = T::MinRewardTurnout::get().mul_ceil(T::Currency::total_issuance());
+            let amount =
+                funds.saturating_add(T::ParticipationReward::get()).min(Self::reward_reserve());
+            if amount.is_zero() || turnout.is_zero() || turnout < min_turnout {
+                return false
+            }
+
+            let expires_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::RewardClaimPeriod::get());
+            if RewardsExpiringAt::<T>::try_mutate(&expires_at, |ids| ids.try_push(proposal_id))
+                .is_err()
+            {
+                Self::deposit_event(Event::RewardPoolsFull { proposal_id, expires_at });
+                return false
+            }
+            RewardReserve::<T>::mutate(|reserve| *reserve = reserve.saturating_sub(amount));
+            RewardPools::<T>::insert(
+                proposal_id,
+                RewardPool {
+                    amount,
+                    claimed: Zero::zero(),
+                    turnout,
+                    winning_option,
+                    expires_at,
+                },
+            );
+            OutstandingRewards::<T>::mutate(|outstanding| {
+                *outstanding = outstanding.saturating_add(amount)
+            });
+
+            Self::deposit_event(Event::RewardPoolOpened { proposal_id, amount, expires_at });
+            true
+        }
+
+        /// Return the unclaimed reward of a pool to the reserve and queue the proposal for cleanup
+        fn expire_reward_pool(proposal_id: u32) {
+            let Some(pool) = RewardPools::<T>::take(proposal_id) else { return };
+            let unclaimed = pool.amount.saturating_sub(pool.claimed);
+            OutstandingRewards::<T>::mutate(|outstanding| {
+                *outstanding = outstanding.saturating_sub(unclaimed)
+            });
+            RewardReserve::<T>::mutate(|reserve| *reserve = reserve.saturating_add(unclaimed));
+            if let Some(proposal) = Self::proposals(proposal_id) {
+                Self::queue_cleanup(proposal_id, proposal.status, pool.winning_option);
+            }
+
+            Self::deposit_event(Event::RewardPoolExpired { proposal_id, unclaimed });
+        }
+
+        /// Queue the votes of an ended proposal for removal by `on_idle`
+        pub(crate) fn queue_cleanup(proposal_id: u32, status: ProposalStatus, winning_option: Option<u32>) {
+            CleanupQueue::<T>::insert(
+                proposal_id,
+                CleanupTask {
+                    status,
+                    winning_option,
+                    turnout: Self::turnout(proposal_id),
+                    votes: 0,
+                    stage: CleanupStage::Votes,
+                    cursor: None,
+                },
+            );
+        }
+
+        /// Remove queued votes within `remaining_weight`, archiving proposals once done
+        ///
+        /// Each pass removes a bounded number of keys with `clear_prefix` and keeps the cursor,
+        /// so a proposal with many votes is cleaned up over several blocks. A passed proposal is
+        /// only archived once its call is dispatched, so the call can be vetoed until then.
+        pub(crate) fn cleanup(remaining_weight: Weight) -> Weight {
+            let per_key =
+                T::WeightInfo::clear_votes(1).saturating_sub(T::WeightInfo::clear_votes(0));
+            let reserved = T::WeightInfo::clear_votes(0)
+                .saturating_add(T::WeightInfo::archive_proposal());
+            let mut used = Weight::zero();
+            // Key of the last task waiting for its enactment, the queue continues after it
+            let mut parked: Option<Vec<u8>> = None;
+
+            loop {
+                let available = remaining_weight.saturating_sub(used).saturating_sub(reserved);
+                let limit = (available.ref_time() / per_key.ref_time().max(1))
+                    .min(available.proof_size() / per_key.proof_size().max(1))
+                    .min(u32::MAX as u64) as u32;
+                if limit == 0 {
+                    break
+                }
+                let next = match &parked {
+                    Some(key) => CleanupQueue::<T>::iter_from(key.clone()).next(),
+                    None => CleanupQueue::<T>::iter().next(),
+                };
+                let Some((proposal_id, mut task)) = next else {
+                    used = used.saturating_add(T::DbWeight::get().reads(1));
+                    break
+                };
+
+                let cursor = task.cursor.take().map(|cursor| cursor.into_inner());
+                let cursor = cursor.as_deref();
+                let result = match task.stage {
+                    CleanupStage::Votes => Votes::<T>::clear_prefix(proposal_id, limit, cursor),
+                    CleanupStage::Ballots => Ballots::<T>::clear_prefix(proposal_id, limit, cursor),
+                    CleanupStage::DelegatedVotes =>
+                        DelegatedVotes::<T>::clear_prefix(proposal_id, limit, cursor),
+                    CleanupStage::VoteResults =>
+                        VoteResults::<T>::clear_prefix(proposal_id, limit, cursor),
+                    CleanupStage::RewardClaims =>
+                        RewardClaims::<T>::clear_prefix(proposal_id, limit, cursor),
+                };
+                used = used.saturating_add(T::WeightInfo::clear_votes(result.loops));
+                if task.stage == CleanupStage::Votes {
+                    task.votes = task.votes.saturating_add(result.unique);
+                }
+
+                if let Some(cursor) = result.maybe_cursor {
+                    // Out of weight for this stage, continue from the cursor in a later block
+                    task.cursor = cursor.try_into().ok();
+                    CleanupQueue::<T>::insert(proposal_id, task);
+                    break
+                }
+
+                task.stage = match task.stage {
+                    CleanupStage::Votes => CleanupStage::Ballots,
+                    CleanupStage::Ballots => CleanupStage::DelegatedVotes,
+                    CleanupStage::DelegatedVotes => CleanupStage::VoteResults,
+                    CleanupStage::VoteResults => CleanupStage::RewardClaims,
+                    CleanupStage::RewardClaims => {
+                        if Self::enactment_pending(proposal_id) {
+                            // The call can be vetoed until it is dispatched, archive it afterwards
+                            CleanupQueue::<T>::insert(proposal_id, task);
+                            parked = Some(CleanupQueue::<T>::hashed_key_for(proposal_id));
+                            used = used.saturating_add(T::DbWeight::get().reads(2));
+                            continue
+                        }
+                        Self::archive(proposal_id, task);
+                        used = used.saturating_add(T::WeightInfo::archive_proposal());
+                        continue
+                    },
+                };
+                CleanupQueue::<T>::insert(proposal_id, task);
+            }
+
+            used
+        }
+
+        /// Remove the remaining storage of a cleaned up proposal and announce its summary
+        pub(crate) fn archive(proposal_id: u32, task: CleanupTask<BalanceOf<T>>) {
+            CleanupQueue::<T>::remove(proposal_id);
+            RankedBallots::<T>::remove(proposal_id);
+            Turnout::<T>::remove(proposal_id);
+            ProposalEligibility::<T>::remove(proposal_id);
+            ProposalSeats::<T>::remove(proposal_id);
+            FirstVotes::<T>::remove(proposal_id);
+            ProposalSnapshots::<T>::remove(proposal_id);
+            EnactmentTasks::<T>::remove(proposal_id);
+            VetoReasons::<T>::remove(proposal_id);
+
+            Self::deposit_event(Event::ProposalArchived {
+                proposal_id,
+                status: task.status,
+                winning_option: task.winning_option,
+                turnout: task.turnout,
+                votes: task.votes,
+            });
+        }
+
+        /// Whether the call of a passed proposal is scheduled and not yet dispatched
+        pub(crate) fn enactment_pending(proposal_id: u32) -> bool {
+            Self::enactment_task(proposal_id)
+                .map_or(false, |address| T::Scheduler::next_dispatch_time(address).is_ok())
+        }
+
+        /// Schedule the call of a passed proposal after the enactment delay
+        fn schedule_enactment(proposal_id: u32, call: BoundedCallOf<T>) {
+            let when = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::EnactmentDelay::get());
+            match T::Scheduler::schedule(
+                DispatchTime::At(when),
+                None,
+                63,
+                T::EnactmentOrigin::get(),
+                call,
+            ) {
+                Ok(address) => {
+                    EnactmentTasks::<T>::insert(proposal_id, address);
+                    Self::deposit_event(Event::ProposalEnactmentScheduled { proposal_id, when })
+                },
+                Err(_) => Self::deposit_event(Event::ProposalEnactmentFailed { proposal_id }),
+            }
+        }
+
+        /// Decide the outcome of a proposal from its final tallies
+        ///
+        /// Quorum and approval threshold are measured against the turnout, the weight of all
+        /// votes, which differs from the sum of the tallies in approval and ranked-choice mode.
+        fn tally(
+            tallies: &[BalanceOf<T>],
+            turnout: BalanceOf<T>,
+            proposal: &ProposalInfoOf<T>,
+        ) -> (ProposalStatus, Option<u32>) {
+            if turnout.is_zero() || turnout < proposal.quorum {
+                return (ProposalStatus::NoQuorum, None);
+            }
+
+            // Find the leading option and whether it shares the lead
+            let mut max_votes = BalanceOf::<T>::zero();
+            let mut leading_option = 0u32;
+            let mut tied = false;
+            for (i, votes) in tallies.iter().enumerate() {
+                if *votes > max_votes {
+                    max_votes = *votes;
+                    leading_option = i as u32;
+                    tied = false;
+                } else if *votes == max_votes {
+                    tied = true;
+                }
+            }
+
+            if tied {
+                (ProposalStatus::Tied, None)
+            } else if Perbill::from_rational(max_votes, turnout) >= proposal.approval_threshold {
+                (ProposalStatus::Passed, Some(leading_option))
+            } else {
+                (ProposalStatus::Rejected, None)
+            }
+        }
+
+        /// Elect the `seats` options with the most vote weight
+        ///
+        /// Options level on weight are ranked by which received its first vote earlier. The
+        /// proposal passes if every seat is filled by an option whose share of the turnout reaches
+        /// the approval threshold.
+        fn elect(
+            proposal_id: u32,
+            tallies: &[BalanceOf<T>],
+            turnout: BalanceOf<T>,
+            proposal: &ProposalInfoOf<T>,
+            seats: u32,
+        ) -> (ProposalStatus, Vec<u32>) {
+            if turnout.is_zero() || turnout < proposal.quorum {
+                return (ProposalStatus::NoQuorum, Vec::new());
+            }
+
+            let mut ranked = (0..tallies.len() as u32)
+                .filter(|option| !tallies[*option as usize].is_zero())
+                .collect::<Vec<_>>();
+            let first_votes = Self::first_votes(proposal_id);
+            ranked.sort_by_key(|option| {
+                let first_vote =
+                    first_votes.iter().position(|o| o == option).unwrap_or(usize::MAX);
+                (Reverse(tallies[*option as usize]), first_vote)
+            });
+            ranked.truncate(seats as usize);
+
+            let approved = ranked.iter().all(|option| {
+                Perbill::from_rational(tallies[*option as usize], turnout) >=
+                    proposal.approval_threshold
+            });
+            if ranked.len() < seats as usize || !approved {
+                return (ProposalStatus::Rejected, Vec::new());
+            }
+            (ProposalStatus::Passed, ranked)
+        }
+
+        /// Lock `balance` of `who` until the proposal closes, plus the conviction lock periods
+        fn lock_vote(
+            who: &T::AccountId,
+            proposal_id: u32,
+            proposal: &ProposalInfoOf<T>,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            let lock_duration =
+                T::VoteLockingPeriod::get().saturating_mul(conviction.lock_periods().into());
+            let mut locks = Self::vote_locks(who);
+            locks
+                .try_push(VoteLock {
+                    proposal_id: Some(proposal_id),
+                    amount: balance,
+                    locked_until: Self::closing_block(proposal).saturating_add(lock_duration),
+                })
+                .map_err(|_| Error::<T>::TooManyVotes)?;
+            VoteLocks::<T>::insert(who, locks);
+            T::Currency::extend_lock(
+                VOTING_ID,
+                who,
+                balance,
+                WithdrawReasons::except(WithdrawReasons::RESERVE),
+            );
+            Ok(())
+        }
 
-            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
-            ensure!(proposal.is_active, Error::<T>::ProposalNotActive);
+        /// Check that `who` can cast `ballot` backed by `balance` on an open proposal
+        fn validate_vote(
+            who: &T::AccountId,
+            proposal_id: u32,
+            proposal: &ProposalInfoOf<T>,
+            ballot: Vec<u32>,
+            balance: BalanceOf<T>,
+        ) -> Result<BoundedVec<u32, T::MaxOptions>, DispatchError> {
+            ensure!(proposal.status != ProposalStatus::Pending, Error::<T>::VotingPeriodNotStarted);
+            ensure!(proposal.status == ProposalStatus::Active, Error::<T>::ProposalNotActive);
+            ensure!(!proposal.secret_ballot, Error::<T>::SecretBallot);
+            ensure!(Self::is_eligible(who, proposal_id), Error::<T>::NotEligible);
 
             let current_block = <frame_system::Pallet<T>>::block_number();
             ensure!(current_block >= proposal.start_block, Error::<T>::VotingPeriodNotStarted);
             ensure!(current_block <= proposal.end_block, Error::<T>::VotingPeriodEnded);
 
+            ensure!(!ballot.is_empty(), Error::<T>::EmptyBallot);
+            for (i, option_index) in ballot.iter().enumerate() {
+                ensure!(
+                    *option_index < proposal.options.len() as u32,
+                    Error::<T>::InvalidOptionIndex
+                );
+                ensure!(!ballot[..i].contains(option_index), Error::<T>::DuplicateOption);
+            }
+            let ballot: BoundedVec<u32, T::MaxOptions> =
+                ballot.try_into().map_err(|_| Error::<T>::TooManyOptions)?;
+            if proposal.mode == VotingMode::RankedChoice {
+                let rankings = Self::ranked_ballots(&proposal_id);
+                ensure!(
+                    rankings.len() < T::MaxRankings::get() as usize ||
+                        rankings.iter().any(|(ranking, _)| *ranking == ballot),
+                    Error::<T>::TooManyRankings
+                );
+            }
+
             ensure!(
-                option_index < proposal.options.len() as u32,
-                Error::<T>::InvalidOptionIndex
+                !Votes::<T>::contains_key(&proposal_id, who),
+                Error::<T>::AlreadyVoted
             );
 
+            ensure!(!balance.is_zero(), Error::<T>::ZeroVoteBalance);
             ensure!(
-                !Votes::<T>::contains_key(&proposal_id, &who),
-                Error::<T>::AlreadyVoted
+                balance <= T::Currency::free_balance(who),
+                Error::<T>::InsufficientBalance
+            );
+            ensure!(
+                balance <= Self::snapshot_balance(who, proposal_id),
+                Error::<T>::InsufficientSnapshotBalance
             );
 
+            Ok(ballot)
+        }
+
+        /// Record the vote of `who` and add it, with its delegations, to the tallies
+        ///
+        /// Returns the number of delegations counted.
+        fn count_vote(
+            who: T::AccountId,
+            proposal_id: u32,
+            proposal: &ProposalInfoOf<T>,
+            ballot: BoundedVec<u32, T::MaxOptions>,
+            balance: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> Result<u32, DispatchError> {
+            // Take back the power of the voter if it was already counted through a delegation
+            if let Some((delegate, counted)) = DelegatedVotes::<T>::take(&proposal_id, &who) {
+                if let Some(mut vote) = Self::votes(&proposal_id, &delegate) {
+                    vote.delegated = vote.delegated.saturating_sub(counted);
+                    let delegate_ballot = Self::ballot_of(proposal_id, &delegate, &vote);
+                    Self::remove_weight(proposal_id, proposal.mode, &delegate_ballot, counted);
+                    Votes::<T>::insert(&proposal_id, &delegate, vote);
+                }
+            }
+
+            let delegations = Self::collect_delegations(proposal_id, &who);
+            let mut delegated = BalanceOf::<T>::zero();
+            for (delegator, power) in delegations.iter() {
+                DelegatedVotes::<T>::insert(&proposal_id, delegator, (who.clone(), *power));
+                delegated = delegated.saturating_add(*power);
+            }
+
+            let weight = conviction.votes(balance);
+            let option_index = ballot[0];
             let vote_info = VoteInfo {
                 option_index,
-                weight: 1, // Simple 1-person-1-vote for now
+                balance,
+                conviction,
+                weight,
+                delegated,
             };
 
             Votes::<T>::insert(&proposal_id, &who, &vote_info);
-            
-            // Update vote results
-            let current_count = Self::vote_results(&proposal_id, &option_index);
-            VoteResults::<T>::insert(&proposal_id, &option_index, current_count.saturating_add(1));
+            VotesByAccount::<T>::mutate(&who, |history| {
+                // Forget the oldest vote to make room
+                if history.is_full() {
+                    history.remove(0);
+                }
+                let _ = history.try_push(proposal_id);
+            });
+            Self::add_weight(proposal_id, proposal.mode, &ballot, weight.saturating_add(delegated))?;
+            if proposal.mode != VotingMode::SingleChoice {
+                Ballots::<T>::insert(&proposal_id, &who, ballot);
+            }
 
             Self::deposit_event(Event::VoteCast {
                 proposal_id,
                 voter: who,
                 option_index,
+                conviction,
+                weight,
+                delegated,
             });
 
-            Ok(())
+            Ok(delegations.len() as u32)
         }
 
-        /// End a proposal (can be called by anyone after voting period)
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::end_proposal())]
-        pub fn end_proposal(
-            origin: OriginFor<T>,
+        /// Ballot of a recorded vote
+        fn ballot_of(
+            proposal_id: u32,
+            who: &T::AccountId,
+            vote: &VoteInfo<BalanceOf<T>>,
+        ) -> BoundedVec<u32, T::MaxOptions> {
+            Self::ballots(&proposal_id, who)
+                .unwrap_or_else(|| BoundedVec::truncate_from(sp_std::vec![vote.option_index]))
+        }
+
+        /// Add `amount` of vote weight behind `ballot` to the tallies of a proposal
+        fn add_weight(
             proposal_id: u32,
+            mode: VotingMode,
+            ballot: &BoundedVec<u32, T::MaxOptions>,
+            amount: BalanceOf<T>,
         ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+            let options = match mode {
+                VotingMode::Approval => &ballot[..],
+                VotingMode::SingleChoice | VotingMode::RankedChoice => &ballot[..1],
+            };
+            for option_index in options.iter() {
+                VoteResults::<T>::mutate(&proposal_id, option_index, |total| {
+                    *total = total.saturating_add(amount)
+                });
+            }
+            FirstVotes::<T>::mutate(&proposal_id, |first_votes| {
+                for option_index in options.iter() {
+                    if !first_votes.contains(option_index) {
+                        // Options are bounded by `MaxOptions`, so this cannot fail
+                        let _ = first_votes.try_push(*option_index);
+                    }
+                }
+            });
+            if mode == VotingMode::RankedChoice {
+                RankedBallots::<T>::try_mutate(&proposal_id, |rankings| {
+                    match rankings.iter_mut().find(|(ranking, _)| ranking == ballot) {
+                        Some((_, weight)) => {
+                            *weight = weight.saturating_add(amount);
+                            Ok(())
+                        },
+                        None => rankings
+                            .try_push((ballot.clone(), amount))
+                            .map_err(|_| Error::<T>::TooManyRankings),
+                    }
+                })?;
+            }
+            Turnout::<T>::mutate(&proposal_id, |total| *total = total.saturating_add(amount));
+            Ok(())
+        }
 
-            let mut proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
-            ensure!(proposal.is_active, Error::<T>::ProposalNotActive);
+        /// Remove `amount` of vote weight behind `ballot` from the tallies of a proposal
+        fn remove_weight(
+            proposal_id: u32,
+            mode: VotingMode,
+            ballot: &BoundedVec<u32, T::MaxOptions>,
+            amount: BalanceOf<T>,
+        ) {
+            let options = match mode {
+                VotingMode::Approval => &ballot[..],
+                VotingMode::SingleChoice | VotingMode::RankedChoice => &ballot[..1],
+            };
+            for option_index in options.iter() {
+                VoteResults::<T>::mutate(&proposal_id, option_index, |total| {
+                    *total = total.saturating_sub(amount)
+                });
+            }
+            if mode == VotingMode::RankedChoice {
+                RankedBallots::<T>::mutate(&proposal_id, |rankings| {
+                    if let Some((_, weight)) =
+                        rankings.iter_mut().find(|(ranking, _)| ranking == ballot)
+                    {
+                        *weight = weight.saturating_sub(amount);
+                    }
+                    rankings.retain(|(_, weight)| !weight.is_zero());
+                });
+            }
+            Turnout::<T>::mutate(&proposal_id, |total| *total = total.saturating_sub(amount));
+        }
 
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            ensure!(current_block > proposal.end_block, Error::<T>::VotingPeriodEnded);
+        /// Final round tallies of the instant-runoff count of a ranked-choice proposal
+        ///
+        /// Each round counts every ranking for its most preferred option still in the race and
+        /// eliminates the option with the least weight, the highest index among equals, until
+        /// an option holds a majority, all remaining options are level or one option is left.
+        pub(crate) fn instant_runoff(proposal_id: u32, options: u32) -> Vec<BalanceOf<T>> {
+            let rankings = Self::ranked_ballots(&proposal_id);
+            let options = options as usize;
+            let mut eliminated = sp_std::vec![false; options];
 
-            proposal.is_active = false;
-            Proposals::<T>::insert(&proposal_id, &proposal);
+            loop {
+                let mut round = sp_std::vec![BalanceOf::<T>::zero(); options];
+                for (ranking, weight) in rankings.iter() {
+                    if let Some(option_index) =
+                        ranking.iter().find(|i| !eliminated[**i as usize])
+                    {
+                        let tally = &mut round[*option_index as usize];
+                        *tally = tally.saturating_add(*weight);
+                    }
+                }
 
-            // Find winning option
-            let mut max_votes = 0u32;
-            let mut winning_option: Option<u32> = None;
+                let continuing: Vec<usize> = (0..options).filter(|i| !eliminated[*i]).collect();
+                let total = round
+                    .iter()
+                    .fold(BalanceOf::<T>::zero(), |acc, weight| acc.saturating_add(*weight));
+                let leader = continuing.iter().map(|i| round[*i]).max().unwrap_or_else(Zero::zero);
+                let level = continuing.iter().all(|i| round[*i] == leader);
+                if continuing.len() <= 1 || level || leader.saturating_mul(2u8.into()) > total {
+                    return round
+                }
 
-            for i in 0..proposal.options.len() as u32 {
-                let votes = Self::vote_results(&proposal_id, &i);
-                if votes > max_votes {
-                    max_votes = votes;
-                    winning_option = Some(i);
+                let mut loser = continuing[0];
+                for i in continuing.iter() {
+                    if round[*i] <= round[loser] {
+                        loser = *i;
+                    }
                 }
+                eliminated[loser] = true;
             }
+        }
 
-            Self::deposit_event(Event::ProposalEnded {
-                proposal_id,
-                winning_option,
-            });
+        /// Minimum quorum of a proposal with a call at the current total issuance
+        pub fn enactment_quorum() -> BalanceOf<T> {
+            T::MinEnactmentQuorum::get().mul_ceil(T::Currency::total_issuance())
+        }
 
-            Ok(())
+        /// Last block of the proposal's voting period, including the reveal window of secret ballots
+        pub fn closing_block(proposal: &ProposalInfoOf<T>) -> BlockNumberFor<T> {
+            if proposal.secret_ballot {
+                proposal.end_block.saturating_add(T::RevealPeriod::get())
+            } else {
+                proposal.end_block
+            }
         }
 
-        /// Cancel a proposal (only by proposer)
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::cancel_proposal())]
-        pub fn cancel_proposal(
-            origin: OriginFor<T>,
+        /// Delegation of `who` in exactly `scope`
+        pub fn delegation_of(
+            who: &T::AccountId,
+            scope: DelegationScope,
+        ) -> Option<Delegation<T::AccountId, BalanceOf<T>>> {
+            Self::delegations(who).into_iter().find(|delegation| delegation.scope == scope)
+        }
+
+        /// Delegations counting towards the vote of `voter` on a proposal, with their weight
+        ///
+        /// Walks the delegation tree of the voter breadth first, at most `MaxDelegationDepth`
+        /// levels deep and up to `MaxDelegators` delegations. Delegators who voted or committed,
+        /// whose power is already counted elsewhere, who are not eligible to vote on the proposal
+        /// or who delegate this proposal to someone else are skipped together with their own
+        /// delegators, which also cuts cycles.
+        pub(crate) fn collect_delegations(
             proposal_id: u32,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            voter: &T::AccountId,
+        ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let max = T::MaxDelegators::get() as usize;
+            let mut counted: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+            let mut frontier = sp_std::vec![voter.clone()];
 
-            let mut proposal = Self::proposals(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
-            ensure!(proposal.is_active, Error::<T>::ProposalNotActive);
-            ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+            for _ in 0..T::MaxDelegationDepth::get() {
+                let mut next = Vec::new();
+                for account in frontier.iter() {
+                    for scope in [DelegationScope::Proposal(proposal_id), DelegationScope::All] {
+                        for delegator in Self::delegators(account, scope) {
+                            if counted.len() >= max {
+                                return counted
+                            }
+                            if delegator == *voter ||
+                                counted.iter().any(|(counted, _)| *counted == delegator) ||
+                                Votes::<T>::contains_key(&proposal_id, &delegator) ||
+                                Commitments::<T>::contains_key(&proposal_id, &delegator) ||
+                                DelegatedVotes::<T>::contains_key(&proposal_id, &delegator) ||
+                                !Self::is_eligible(&delegator, proposal_id)
+                            {
+                                continue
+                            }
+                            // A delegation scoped to the proposal takes precedence over `All`
+                            let delegation = match Self::delegation_of(
+                                &delegator,
+                                DelegationScope::Proposal(proposal_id),
+                            ) {
+                                Some(delegation) => delegation,
+                                None => match Self::delegation_of(&delegator, scope) {
+                                    Some(delegation) => delegation,
+                                    None => continue,
+                                },
+                            };
+                            if delegation.target != *account {
+                                continue
+                            }
+                            counted.push((
+                                delegator.clone(),
+                                delegation.conviction.votes(
+                                    delegation
+                                        .balance
+                                        .min(Self::snapshot_balance(&delegator, proposal_id)),
+                                ),
+                            ));
+                            next.push(delegator);
+                        }
+                    }
+                }
+                if next.is_empty() {
+                    break
+                }
+                frontier = next;
+            }
 
-            proposal.is_active = false;
-            Proposals::<T>::insert(&proposal_id, &proposal);
+            counted
+        }
 
-            Self::deposit_event(Event::ProposalCancelled {
-                proposal_id,
+        /// Whether `who` may vote on a proposal under its eligibility rule
+        pub fn is_eligible(who: &T::AccountId, proposal_id: u32) -> bool {
+            match Self::eligibility_rule(proposal_id) {
+                None | Some(EligibilityRule::Open) => true,
+                Some(EligibilityRule::Members) => T::Eligibility::is_member(who),
+                Some(EligibilityRule::AssetHolder { asset_id, min_balance }) => {
+                    let balance = match Self::snapshot_block(proposal_id) {
+                        Some(at) => Self::asset_balance_at(asset_id, who, at),
+                        None => T::Eligibility::asset_balance(asset_id, who),
+                    };
+                    balance >= min_balance
+                },
+                Some(EligibilityRule::Allowlist(accounts)) => accounts.contains(who),
+            }
+        }
+
+        /// Balance weighing the votes of `who` on a proposal, as of its snapshot block
+        pub fn snapshot_balance(who: &T::AccountId, proposal_id: u32) -> BalanceOf<T> {
+            match Self::snapshot_block(proposal_id) {
+                Some(at) => Self::balance_at(who, at),
+                None => T::Currency::free_balance(who),
+            }
+        }
+
+        /// Free balance of `who` at the start of block `at`
+        pub fn balance_at(who: &T::AccountId, at: BlockNumberFor<T>) -> BalanceOf<T> {
+            Self::native_checkpoints(who)
+                .into_iter()
+                .find(|(block, _)| *block >= at)
+                .map(|(_, balance)| balance)
+                .unwrap_or_else(|| T::Currency::free_balance(who))
+        }
+
+        /// Balance `who` held of an approved asset at the start of block `at`
+        pub fn asset_balance_at(asset_id: u32, who: &T::AccountId, at: BlockNumberFor<T>) -> u128 {
+            Self::asset_checkpoints(asset_id, who)
+                .into_iter()
+                .find(|(block, _)| *block >= at)
+                .map(|(_, balance)| balance)
+                .unwrap_or_else(|| T::Eligibility::asset_balance(asset_id, who))
+        }
+
+        /// Record the free balance of `who` before its first change in the current block
+        pub fn note_balance_change(who: &T::AccountId, previous: BalanceOf<T>) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            if now.is_zero() {
+                return
+            }
+            NativeCheckpoints::<T>::mutate(who, |checkpoints| {
+                Self::push_checkpoint(checkpoints, now, previous)
             });
+        }
 
-            Ok(())
+        /// Record the asset balance of `who` before its first change in the current block
+        pub fn note_asset_balance_change(asset_id: u32, who: &T::AccountId, previous: u128) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            if now.is_zero() {
+                return
+            }
+            AssetCheckpoints::<T>::mutate(asset_id, who, |checkpoints| {
+                Self::push_checkpoint(checkpoints, now, previous)
+            });
+        }
+
+        /// Add a checkpoint, dropping those too old to matter for any open proposal
+        ///
+        /// A proposal is voted on at most `MaxStartDelay + MaxVotingPeriod + RevealPeriod` blocks
+        /// after its snapshot. If an account changes balance more often than `MaxCheckpoints`
+        /// times in that window, its two oldest checkpoints are merged into the later block with
+        /// the lower balance, so a snapshot before it under-reports rather than over-reports.
+        fn push_checkpoint<Balance: Ord + Copy>(
+            checkpoints: &mut CheckpointsOf<T, Balance>,
+            now: BlockNumberFor<T>,
+            previous: Balance,
+        ) {
+            if checkpoints.last().map_or(false, |(block, _)| *block == now) {
+                return
+            }
+            let horizon = now.saturating_sub(
+                T::MaxStartDelay::get()
+                    .saturating_add(T::MaxVotingPeriod::get())
+                    .saturating_add(T::RevealPeriod::get()),
+            );
+            checkpoints.retain(|(block, _)| *block >= horizon);
+            if checkpoints.is_full() {
+                let (_, oldest) = checkpoints.remove(0);
+                if let Some((_, balance)) = checkpoints.get_mut(0) {
+                    *balance = (*balance).min(oldest);
+                }
+            }
+            let _ = checkpoints.try_push((now, previous));
         }
-    }
 
-    impl<T: Config> Pallet<T> {
         /// Get proposal details
-        pub fn get_proposal(proposal_id: u32) -> Option<ProposalInfo<T::AccountId, BlockNumberFor<T>>> {
+        pub fn get_proposal(proposal_id: u32) -> Option<ProposalInfoOf<T>> {
             Self::proposals(&proposal_id)
         }
 
         /// Get vote results for a proposal
-        pub fn get_vote_results(proposal_id: u32) -> Vec<u32> {
+        pub fn get_vote_results(proposal_id: u32) -> Vec<BalanceOf<T>> {
             let proposal = match Self::proposals(&proposal_id) {
                 Some(p) => p,
                 None => return Vec::new(),
@@ -368,9 +2876,32 @@ pub mod pallet {
             results
         }
 
+        /// Active proposals with an ID in `start..start + limit`
+        ///
+        /// Only `limit` IDs are read, the next page starts at `start + limit`.
+        pub fn active_proposals(start: u32, limit: u32) -> Vec<(u32, ProposalInfoOf<T>)> {
+            let end = start.saturating_add(limit).min(Self::next_proposal_id());
+            (start..end)
+                .filter_map(|proposal_id| {
+                    Self::proposals(&proposal_id).map(|proposal| (proposal_id, proposal))
+                })
+                .filter(|(_, proposal)| proposal.status == ProposalStatus::Active)
+                .collect()
+        }
+
         /// Check if user has voted on a proposal
         pub fn has_voted(proposal_id: u32, account: &T::AccountId) -> bool {
             Votes::<T>::contains_key(&proposal_id, account)
         }
+
+        /// Proposals `who` has voted on, most recent first, `VOTER_HISTORY_PAGE_SIZE` per page
+        pub fn voter_history(who: &T::AccountId, page: u32) -> Vec<u32> {
+            Self::votes_by_account(who)
+                .into_iter()
+                .rev()
+                .skip(page.saturating_mul(VOTER_HISTORY_PAGE_SIZE) as usize)
+                .take(VOTER_HISTORY_PAGE_SIZE as usize)
+                .collect()
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,316 @@
+//! Storage migrations for the voting pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade, BoundedVec};
+use sp_runtime::{
+    traits::{One, Zero},
+    Perbill,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Storage layout before version 1, with unbounded proposals and votes counted one each.
+pub mod v0 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// Proposal information before version 1
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    pub struct OldProposalInfo<AccountId, BlockNumber> {
+        pub proposer: AccountId,
+        pub description: Vec<u8>,
+        pub options: Vec<Vec<u8>>,
+        pub start_block: BlockNumber,
+        pub end_block: BlockNumber,
+        pub is_active: bool,
+    }
+
+    pub type OldProposalInfoOf<T> =
+        OldProposalInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// Vote information before version 1, every vote weighs one
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    pub struct OldVoteInfo {
+        pub option_index: u32,
+        pub weight: u32,
+    }
+
+    #[storage_alias]
+    pub type Proposals<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, u32, OldProposalInfoOf<T>, OptionQuery>;
+
+    #[storage_alias]
+    pub type Votes<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        OldVoteInfo,
+        OptionQuery,
+    >;
+
+    #[storage_alias]
+    pub type VoteResults<T: Config> =
+        StorageDoubleMap<Pallet<T>, Blake2_128Concat, u32, Blake2_128Concat, u32, u32, ValueQuery>;
+}
+
+/// Migrate proposals to bounded descriptions and options.
+pub mod v1 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// Proposal information before version 2, without the proposer's deposit
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct OldProposalInfo<AccountId, Balance, BlockNumber, Call, Description, Options> {
+        pub proposer: AccountId,
+        pub description: Description,
+        pub options: Options,
+        pub call: Option<Call>,
+        pub start_block: BlockNumber,
+        pub end_block: BlockNumber,
+        pub quorum: Balance,
+        pub approval_threshold: Perbill,
+        pub status: ProposalStatus,
+        pub secret_ballot: bool,
+        pub mode: VotingMode,
+    }
+
+    /// Proposal information with a bounded description and options
+    pub type OldProposalInfoOf<T> = OldProposalInfo<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        BoundedCallOf<T>,
+        DescriptionOf<T>,
+        OptionsOf<T>,
+    >;
+
+    #[storage_alias]
+    pub type Proposals<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, u32, OldProposalInfoOf<T>, OptionQuery>;
+
+    /// Bounds the description and options of every stored proposal and weighs votes in balance.
+    ///
+    /// Proposals were created with at most `MaxOptions` options and a description of at most
+    /// `MaxDescriptionLength` bytes, so only options longer than `MaxOptionLength` are truncated.
+    /// Votes keep their count as weight without any locked balance, and the tallies of each
+    /// proposal are summed into its `Turnout`.
+    ///
+    /// Proposals were decided by plurality, so they keep a zero quorum and approval threshold.
+    /// Version 0 only recorded whether a proposal was active: an inactive proposal closed before
+    /// its end block was cancelled, any other is assumed to have ended with the outcome of its
+    /// tallies. Active proposals are indexed in `ProposalsEndingAt` by their end block, or by the
+    /// current block if it has already passed, so `on_initialize` ends them.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> MigrateToV1<T> {
+        /// Status of a proposal closed before the migration
+        fn closed_status(proposal_id: u32, old: &v0::OldProposalInfoOf<T>) -> ProposalStatus {
+            if frame_system::Pallet::<T>::block_number() <= old.end_block {
+                return ProposalStatus::Cancelled
+            }
+            let tallies = (0..old.options.len() as u32)
+                .map(|option_index| VoteResults::<T>::get(proposal_id, option_index))
+                .collect::<Vec<_>>();
+            let Some(max_votes) = tallies.iter().max().copied() else {
+                return ProposalStatus::NoQuorum
+            };
+            if max_votes.is_zero() {
+                ProposalStatus::NoQuorum
+            } else if tallies.iter().filter(|votes| **votes == max_votes).count() > 1 {
+                ProposalStatus::Tied
+            } else {
+                ProposalStatus::Passed
+            }
+        }
+
+        /// Index an active proposal to end in the first block after `end_block` that still runs
+        ///
+        /// Full blocks push the proposal to the block after, so no proposal is left unindexed.
+        fn index_ending(proposal_id: u32, end_block: BlockNumberFor<T>) -> u64 {
+            let mut block = end_block.max(frame_system::Pallet::<T>::block_number());
+            let mut reads = 1u64;
+            while ProposalsEndingAt::<T>::mutate(&block, |ids| ids.try_push(proposal_id)).is_err() {
+                block = block.saturating_add(One::one());
+                reads += 1;
+            }
+            reads
+        }
+    }
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut votes = 0u64;
+            Votes::<T>::translate::<v0::OldVoteInfo, _>(|_, _, old| {
+                votes += 1;
+                Some(VoteInfo {
+                    option_index: old.option_index,
+                    balance: Zero::zero(),
+                    conviction: Conviction::None,
+                    weight: old.weight.into(),
+                    delegated: Zero::zero(),
+                })
+            });
+
+            let mut results = 0u64;
+            VoteResults::<T>::translate::<u32, _>(|proposal_id, _, old| {
+                results += 1;
+                Turnout::<T>::mutate(proposal_id, |turnout| {
+                    *turnout = turnout.saturating_add(old.into())
+                });
+                Some(old.into())
+            });
+
+            let mut translated = 0u64;
+            let mut active = Vec::new();
+            Proposals::<T>::translate::<v0::OldProposalInfoOf<T>, _>(|proposal_id, old| {
+                translated += 1;
+                let status = if old.is_active {
+                    active.push((proposal_id, old.end_block));
+                    ProposalStatus::Active
+                } else {
+                    Self::closed_status(proposal_id, &old)
+                };
+                Some(OldProposalInfo {
+                    proposer: old.proposer,
+                    description: BoundedVec::truncate_from(old.description),
+                    options: BoundedVec::truncate_from(
+                        old.options.into_iter().map(BoundedVec::truncate_from).collect(),
+                    ),
+                    call: None,
+                    start_block: old.start_block,
+                    end_block: old.end_block,
+                    quorum: Zero::zero(),
+                    approval_threshold: Perbill::zero(),
+                    status,
+                    secret_ballot: false,
+                    mode: VotingMode::SingleChoice,
+                })
+            });
+            let mut index_reads = 0u64;
+            for (proposal_id, end_block) in active {
+                let reads = Self::index_ending(proposal_id, end_block);
+                index_reads = index_reads.saturating_add(reads);
+            }
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            // Tallies also update the turnout, closed proposals read the tallies of their options
+            let entries = translated.saturating_add(votes).saturating_add(results.saturating_mul(2));
+            let tally_reads = translated.saturating_mul(T::MaxOptions::get().into());
+            T::DbWeight::get().reads_writes(
+                entries.saturating_add(tally_reads).saturating_add(index_reads).saturating_add(1),
+                entries.saturating_add(index_reads).saturating_add(1),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let proposals = v0::Proposals::<T>::iter().count() as u32;
+            let votes = v0::Votes::<T>::iter().count() as u32;
+            let results = v0::VoteResults::<T>::iter().count() as u32;
+            Ok((proposals, votes, results).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (proposals, votes, results) = <(u32, u32, u32)>::decode(&mut &state[..])
+                .map_err(|_| "the state parameter should be the number of stored entries")?;
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "storage version should be 1 after the migration"
+            );
+            ensure!(
+                Proposals::<T>::iter().count() as u32 == proposals,
+                "every proposal should decode after the migration"
+            );
+            ensure!(
+                Votes::<T>::iter().count() as u32 == votes,
+                "every vote should decode after the migration"
+            );
+            ensure!(
+                VoteResults::<T>::iter().count() as u32 == results,
+                "every tally should decode after the migration"
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            for (proposal_id, proposal) in Proposals::<T>::iter() {
+                if proposal.status != ProposalStatus::Active {
+                    continue
+                }
+                let indexed = ProposalsEndingAt::<T>::iter().any(|(block, ids)| {
+                    block >= proposal.end_block.min(now) && ids.contains(&proposal_id)
+                });
+                ensure!(indexed, "every active proposal should be indexed to end");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Migrate proposals to record the proposer's deposit.
+pub mod v2 {
+    use super::*;
+
+    /// Adds a zero deposit to every stored proposal.
+    ///
+    /// Proposals created before deposits were introduced reserved nothing, so there is nothing
+    /// to return or slash when they end.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut translated = 0u64;
+            Proposals::<T>::translate::<v1::OldProposalInfoOf<T>, _>(|_, old| {
+                translated += 1;
+                Some(ProposalInfo {
+                    proposer: old.proposer,
+                    description: old.description,
+                    options: old.options,
+                    call: old.call,
+                    start_block: old.start_block,
+                    end_block: old.end_block,
+                    quorum: old.quorum,
+                    approval_threshold: old.approval_threshold,
+                    status: old.status,
+                    secret_ballot: old.secret_ballot,
+                    mode: old.mode,
+                    deposit: Zero::zero(),
+                })
+            });
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let proposals = v1::Proposals::<T>::iter().count() as u32;
+            Ok(proposals.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let proposals = u32::decode(&mut &state[..])
+                .map_err(|_| "the state parameter should be the number of proposals")?;
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "storage version should be 2 after the migration"
+            );
+            ensure!(
+                Proposals::<T>::iter().count() as u32 == proposals,
+                "every proposal should decode after the migration"
+            );
+            Ok(())
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use crate as pallet_voting;
 use frame_support::{
     parameter_types,
-    traits::{ConstU16, ConstU64, ConstU32},
+    traits::{ConstU16, ConstU64, ConstU32, EqualPrivilegeOnly},
+    weights::Weight,
+    PalletId,
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup}, BuildStorage,
+    testing::{TestSignature, UintAuthorityId},
+    traits::{BlakeTwo256, IdentityLookup}, BuildStorage, Perbill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -15,6 +19,9 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler,
         VotingModule: pallet_voting,
     }
 );
@@ -36,7 +43,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -45,23 +52,179 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = pallet_voting::CheckpointedAccountStore<Test, System>;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type MaxHolds = ();
+}
+
+impl pallet_preimage::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<u64>;
+    type BaseDeposit = ConstU64<0>;
+    type ByteDeposit = ConstU64<0>;
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Weight::MAX;
+}
+
+impl pallet_scheduler::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureRoot<u64>;
+    type MaxScheduledPerBlock = ConstU32<100>;
+    type WeightInfo = ();
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
 parameter_types! {
+    pub const MaxVotesPerAccount: u32 = 16;
+    pub const VoteLockingPeriod: u64 = 10;
     pub const MaxOptions: u32 = 10;
     pub const MaxDescriptionLength: u32 = 1000;
+    pub const MaxOptionLength: u32 = 32;
     pub const MinVotingPeriod: u64 = 10;
     pub const MaxVotingPeriod: u64 = 1000;
+    pub const MaxStartDelay: u64 = 100;
+    pub const MaxProposalsEndingPerBlock: u32 = 2;
+    pub const MaxProposalsStartingPerBlock: u32 = 3;
+    pub const MinQuorum: u64 = 5;
+    pub const MinApprovalThreshold: Perbill = Perbill::from_percent(50);
+    pub EnactmentOrigin: OriginCaller = frame_system::RawOrigin::Root.into();
+    pub const EnactmentDelay: u64 = 5;
+    pub const MinEnactmentQuorum: Perbill = Perbill::from_percent(60);
+    pub const MinEnactmentPeriod: u64 = 50;
+    pub const MaxCallLength: u32 = 256;
+    pub const MaxDelegators: u32 = 8;
+    pub const MaxDelegationDepth: u32 = 2;
+    pub const RevealPeriod: u64 = 10;
+    pub const CommitDeposit: u64 = 5;
+    pub const MaxRankings: u32 = 4;
+    pub const MaxAllowlist: u32 = 4;
+    pub const MaxSignedVotes: u32 = 4;
+    pub const MaxVetoReasonLength: u32 = 16;
+    pub const MaxCheckpoints: u32 = 4;
+    pub const MaxVoterHistory: u32 = 4;
+    pub const VotingPalletId: PalletId = PalletId(*b"py/votng");
+    pub const ParticipationReward: u64 = 100;
+    pub const MinRewardTurnout: Perbill = Perbill::from_percent(1);
+    pub const RewardClaimPeriod: u64 = 10;
+    pub const MaxRewardsExpiringPerBlock: u32 = 1;
+    pub const ProposalDeposit: u64 = 5;
+    pub const MinSupport: Perbill = Perbill::from_percent(50);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct VotingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_voting::BenchmarkHelper<UintAuthorityId, TestSignature> for VotingBenchmarkHelper {
+    fn create_public() -> UintAuthorityId {
+        let next = NextBenchmarkKey::get();
+        NextBenchmarkKey::set(next + 1);
+        UintAuthorityId(next)
+    }
+
+    fn sign(public: &UintAuthorityId, message: &[u8]) -> TestSignature {
+        TestSignature(public.0, message.to_vec())
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+    pub static NextBenchmarkKey: u64 = 1000;
+}
+
+/// Accounts 1 to 3 are members, account `n` holds `10 * n` of asset 0.
+pub struct TestEligibility;
+
+impl pallet_voting::VoterEligibility<u64> for TestEligibility {
+    fn is_member(who: &u64) -> bool {
+        (1..=3).contains(who)
+    }
+
+    fn asset_balance(asset_id: u32, who: &u64) -> u128 {
+        if asset_id == 0 {
+            *who as u128 * 10
+        } else {
+            0
+        }
+    }
 }
 
 impl pallet_voting::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type Currency = Balances;
+    type MaxVotesPerAccount = MaxVotesPerAccount;
+    type VoteLockingPeriod = VoteLockingPeriod;
     type MaxOptions = MaxOptions;
     type MaxDescriptionLength = MaxDescriptionLength;
+    type MaxOptionLength = MaxOptionLength;
     type MinVotingPeriod = MinVotingPeriod;
     type MaxVotingPeriod = MaxVotingPeriod;
+    type MaxStartDelay = MaxStartDelay;
+    type MaxProposalsEndingPerBlock = MaxProposalsEndingPerBlock;
+    type MaxProposalsStartingPerBlock = MaxProposalsStartingPerBlock;
+    type MinQuorum = MinQuorum;
+    type MinApprovalThreshold = MinApprovalThreshold;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type MaxCallLength = MaxCallLength;
+    type EnactmentOrigin = EnactmentOrigin;
+    type EnactmentDelay = EnactmentDelay;
+    type MinEnactmentQuorum = MinEnactmentQuorum;
+    type MinEnactmentPeriod = MinEnactmentPeriod;
+    type MaxDelegators = MaxDelegators;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type RevealPeriod = RevealPeriod;
+    type CommitDeposit = CommitDeposit;
+    type MaxRankings = MaxRankings;
+    type Eligibility = TestEligibility;
+    type MaxAllowlist = MaxAllowlist;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type MaxSignedVotes = MaxSignedVotes;
+    type CancelOrigin = EnsureRoot<u64>;
+    type VetoOrigin = EnsureRoot<u64>;
+    type MaxVetoReasonLength = MaxVetoReasonLength;
+    type MaxCheckpoints = MaxCheckpoints;
+    type MaxVoterHistory = MaxVoterHistory;
+    type PalletId = VotingPalletId;
+    type ParticipationReward = ParticipationReward;
+    type MinRewardTurnout = MinRewardTurnout;
+    type RewardClaimPeriod = RewardClaimPeriod;
+    type MaxRewardsExpiringPerBlock = MaxRewardsExpiringPerBlock;
+    type ProposalDeposit = ProposalDeposit;
+    type MinSupport = MinSupport;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = VotingBenchmarkHelper;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=10).map(|who| (who, 100)).collect(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
 }
\ No newline at end of file
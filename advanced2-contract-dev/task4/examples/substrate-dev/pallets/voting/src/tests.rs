@@ -1,5 +1,172 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok, traits::Get};
+use crate::{
+    migrations::{
+        v0::{self, OldVoteInfo},
+        v1::{self, MigrateToV1, OldProposalInfo},
+        v2::MigrateToV2,
+    },
+    mock::*,
+    Conviction, DelegationScope, EligibilityRule, EligibilityRuleOf, Error, Event, ProposalParams,
+    ProposalParamsOf, ProposalStatus, VotingMode, WeightInfo, SIGNED_VOTE_CONTEXT,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        Currency, Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion,
+    },
+    weights::Weight,
+};
+use codec::Encode;
+use sp_core::H256;
+use sp_runtime::{
+    testing::TestSignature,
+    traits::{BlakeTwo256, Hash},
+    DispatchResult, Perbill,
+};
+
+/// Builds a proposal from the defaults most tests share, overriding only what a test is about
+struct ProposalBuilder {
+    proposer: u64,
+    params: ProposalParamsOf<Test>,
+    votes: Vec<(u64, u32, u64)>,
+    reward: u64,
+}
+
+impl ProposalBuilder {
+    fn new() -> Self {
+        Self {
+            proposer: 1,
+            params: ProposalParams {
+                description: b"Test proposal".to_vec(),
+                options: vec![b"Option A".to_vec(), b"Option B".to_vec()],
+                voting_period: 100,
+                start_delay: 0,
+                quorum: 10,
+                approval_threshold: Perbill::from_percent(50),
+                call: None,
+                secret_ballot: false,
+                mode: VotingMode::SingleChoice,
+                eligibility: EligibilityRule::Open,
+                seats: 1,
+            },
+            votes: Vec::new(),
+            reward: 0,
+        }
+    }
+
+    fn proposer(mut self, proposer: u64) -> Self {
+        self.proposer = proposer;
+        self
+    }
+
+    fn description(mut self, description: Vec<u8>) -> Self {
+        self.params.description = description;
+        self
+    }
+
+    fn options(mut self, options: Vec<Vec<u8>>) -> Self {
+        self.params.options = options;
+        self
+    }
+
+    fn voting_period(mut self, voting_period: u64) -> Self {
+        self.params.voting_period = voting_period;
+        self
+    }
+
+    fn start_delay(mut self, start_delay: u64) -> Self {
+        self.params.start_delay = start_delay;
+        self
+    }
+
+    fn quorum(mut self, quorum: u64) -> Self {
+        self.params.quorum = quorum;
+        self
+    }
+
+    fn approval_threshold(mut self, approval_threshold: Perbill) -> Self {
+        self.params.approval_threshold = approval_threshold;
+        self
+    }
+
+    fn call(mut self, call: RuntimeCall) -> Self {
+        self.params.call = Some(Box::new(call));
+        self
+    }
+
+    fn secret_ballot(mut self) -> Self {
+        self.params.secret_ballot = true;
+        self
+    }
+
+    fn mode(mut self, mode: VotingMode) -> Self {
+        self.params.mode = mode;
+        self
+    }
+
+    fn eligibility(mut self, eligibility: EligibilityRuleOf<Test>) -> Self {
+        self.params.eligibility = eligibility;
+        self
+    }
+
+    fn seats(mut self, seats: u32) -> Self {
+        self.params.seats = seats;
+        self
+    }
+
+    /// `(voter, option, balance)` votes cast with `Locked1x` by `end`
+    fn votes(mut self, votes: &[(u64, u32, u64)]) -> Self {
+        self.votes = votes.to_vec();
+        self
+    }
+
+    /// Participation reward earmarked for the proposal by `end`
+    fn reward(mut self, reward: u64) -> Self {
+        self.reward = reward;
+        self
+    }
+
+    fn create(self) -> DispatchResult {
+        VotingModule::create_proposal(RuntimeOrigin::signed(self.proposer), self.params)
+    }
+
+    /// Create the proposal as proposal 0, cast the votes and end it after its voting period
+    fn end(self) {
+        let end_block = System::block_number() + self.params.voting_period + 1;
+        let votes = self.votes.clone();
+        let reward = self.reward;
+        assert_ok!(self.create());
+        if reward > 0 {
+            fund_reward(0, reward);
+        }
+        for (voter, option_index, balance) in votes {
+            assert_ok!(VotingModule::vote(
+                RuntimeOrigin::signed(voter),
+                0,
+                option_index,
+                balance,
+                Conviction::Locked1x
+            ));
+        }
+
+        System::set_block_number(end_block);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+    }
+}
+
+/// Parameters of a plain single-choice proposal, as created by the original tests
+fn proposal_params(
+    description: Vec<u8>,
+    options: Vec<Vec<u8>>,
+    voting_period: u64,
+) -> ProposalParamsOf<Test> {
+    ProposalParams { description, options, voting_period, ..ProposalBuilder::new().params }
+}
+
+/// Earmark `amount` for the voters of `proposal_id` from account 30, as a treasury would
+fn fund_reward(proposal_id: u32, amount: u64) {
+    Balances::make_free_balance_be(&30, amount + 1);
+    assert_ok!(VotingModule::fund_reward(RuntimeOrigin::signed(30), proposal_id, amount));
+}
 
 #[test]
 fn create_proposal_works() {
@@ -12,19 +179,22 @@ fn create_proposal_works() {
 
         assert_ok!(VotingModule::create_proposal(
             RuntimeOrigin::signed(1),
-            description.clone(),
-            options.clone(),
-            voting_period
+            proposal_params(description.clone(), options.clone(), voting_period)
         ));
 
         // Check that the proposal was created
         let proposal = VotingModule::proposals(0).unwrap();
         assert_eq!(proposal.proposer, 1);
         assert_eq!(proposal.description, description);
-        assert_eq!(proposal.options, options);
+        assert_eq!(
+            proposal.options.into_iter().map(|option| option.into_inner()).collect::<Vec<_>>(),
+            options
+        );
         assert_eq!(proposal.start_block, 1);
         assert_eq!(proposal.end_block, 101);
-        assert_eq!(proposal.is_active, true);
+        assert_eq!(proposal.status, ProposalStatus::Active);
+        assert_eq!(proposal.quorum, 10);
+        assert_eq!(proposal.approval_threshold, Perbill::from_percent(50));
 
         // Check that the next proposal ID was incremented
         assert_eq!(VotingModule::next_proposal_id(), 1);
@@ -48,9 +218,7 @@ fn create_proposal_fails_with_no_options() {
         assert_noop!(
             VotingModule::create_proposal(
                 RuntimeOrigin::signed(1),
-                description,
-                options,
-                voting_period
+                proposal_params(description, options, voting_period)
             ),
             Error::<Test>::NoOptions
         );
@@ -70,9 +238,7 @@ fn create_proposal_fails_with_too_many_options() {
         assert_noop!(
             VotingModule::create_proposal(
                 RuntimeOrigin::signed(1),
-                description,
-                options,
-                voting_period
+                proposal_params(description, options, voting_period)
             ),
             Error::<Test>::TooManyOptions
         );
@@ -89,15 +255,25 @@ fn create_proposal_fails_with_description_too_long() {
         assert_noop!(
             VotingModule::create_proposal(
                 RuntimeOrigin::signed(1),
-                description,
-                options,
-                voting_period
+                proposal_params(description, options, voting_period)
             ),
             Error::<Test>::DescriptionTooLong
         );
     });
 }
 
+#[test]
+fn create_proposal_fails_with_option_too_long() {
+    new_test_ext().execute_with(|| {
+        let options = vec![b"Option A".to_vec(), vec![b'a'; (MaxOptionLength::get() + 1) as usize]];
+
+        assert_noop!(
+            ProposalBuilder::new().options(options).create(),
+            Error::<Test>::OptionTooLong
+        );
+    });
+}
+
 #[test]
 fn create_proposal_fails_with_invalid_voting_period() {
     new_test_ext().execute_with(|| {
@@ -108,9 +284,7 @@ fn create_proposal_fails_with_invalid_voting_period() {
         assert_noop!(
             VotingModule::create_proposal(
                 RuntimeOrigin::signed(1),
-                description.clone(),
-                options.clone(),
-                5u64
+                proposal_params(description.clone(), options.clone(), 5u64)
             ),
             Error::<Test>::InvalidVotingPeriod
         );
@@ -119,15 +293,82 @@ fn create_proposal_fails_with_invalid_voting_period() {
         assert_noop!(
             VotingModule::create_proposal(
                 RuntimeOrigin::signed(1),
-                description,
-                options,
-                2000u64
+                proposal_params(description, options, 2000u64)
             ),
             Error::<Test>::InvalidVotingPeriod
         );
     });
 }
 
+#[test]
+fn scheduled_proposal_opens_at_start_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().start_delay(5).create());
+
+        let proposal = VotingModule::proposals(0).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+        assert_eq!(proposal.start_block, 6);
+        assert_eq!(proposal.end_block, 106);
+        assert_eq!(VotingModule::proposals_starting_at(6).into_inner(), vec![0]);
+        assert_eq!(VotingModule::proposals_ending_at(106).into_inner(), vec![0]);
+
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 40, Conviction::None),
+            Error::<Test>::VotingPeriodNotStarted
+        );
+
+        System::set_block_number(6);
+        VotingModule::on_initialize(6);
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Active);
+        assert!(VotingModule::proposals_starting_at(6).is_empty());
+        System::assert_last_event(Event::ProposalOpened { proposal_id: 0 }.into());
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 40, Conviction::None));
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_start_delay_too_long() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            ProposalBuilder::new().start_delay(MaxStartDelay::get() + 1).create(),
+            Error::<Test>::StartDelayTooLong
+        );
+        assert_ok!(ProposalBuilder::new().start_delay(MaxStartDelay::get()).create());
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_too_many_proposals_starting_at() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // Ending in different blocks, only the start block is full
+        for voting_period in 100..100 + MaxProposalsStartingPerBlock::get() as u64 {
+            assert_ok!(ProposalBuilder::new().start_delay(5).voting_period(voting_period).create());
+        }
+        assert_noop!(
+            ProposalBuilder::new().start_delay(5).voting_period(110).create(),
+            Error::<Test>::TooManyProposalsStartingAt
+        );
+    });
+}
+
+#[test]
+fn cancel_pending_proposal_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().start_delay(5).create());
+
+        assert_ok!(VotingModule::cancel_proposal(RuntimeOrigin::signed(1), 0));
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Cancelled);
+        assert!(VotingModule::proposals_starting_at(6).is_empty());
+        assert!(VotingModule::proposals_ending_at(106).is_empty());
+    });
+}
+
 #[test]
 fn vote_works() {
     new_test_ext().execute_with(|| {
@@ -140,32 +381,44 @@ fn vote_works() {
 
         assert_ok!(VotingModule::create_proposal(
             RuntimeOrigin::signed(1),
-            description,
-            options,
-            voting_period
+            proposal_params(description, options, voting_period)
         ));
 
         // Vote on the proposal
         assert_ok!(VotingModule::vote(
             RuntimeOrigin::signed(2),
             0,
-            0
+            0,
+            40,
+            Conviction::Locked1x
         ));
 
         // Check that the vote was recorded
         let vote = VotingModule::votes(0, 2).unwrap();
         assert_eq!(vote.option_index, 0);
-        assert_eq!(vote.weight, 1);
+        assert_eq!(vote.balance, 40);
+        assert_eq!(vote.conviction, Conviction::Locked1x);
+        assert_eq!(vote.weight, 40);
 
         // Check that the vote result was updated
-        assert_eq!(VotingModule::vote_results(0, 0), 1);
+        assert_eq!(VotingModule::vote_results(0, 0), 40);
         assert_eq!(VotingModule::vote_results(0, 1), 0);
 
+        // Check that the voted balance is locked for one period after the proposal ends
+        let locks = VotingModule::vote_locks(2);
+        assert_eq!(locks.len(), 1);
+        assert_eq!(locks[0].amount, 40);
+        assert_eq!(locks[0].locked_until, 101 + VoteLockingPeriod::get());
+        assert_eq!(Balances::usable_balance(2), 60);
+
         // Check that the event was emitted
         System::assert_last_event(Event::VoteCast {
             proposal_id: 0,
             voter: 2,
             option_index: 0,
+            conviction: Conviction::Locked1x,
+            weight: 40,
+            delegated: 0,
         }.into());
     });
 }
@@ -174,7 +427,7 @@ fn vote_works() {
 fn vote_fails_with_nonexistent_proposal() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            VotingModule::vote(RuntimeOrigin::signed(1), 0, 0),
+            VotingModule::vote(RuntimeOrigin::signed(1), 0, 0, 10, Conviction::Locked1x),
             Error::<Test>::ProposalNotFound
         );
     });
@@ -192,14 +445,12 @@ fn vote_fails_with_invalid_option() {
 
         assert_ok!(VotingModule::create_proposal(
             RuntimeOrigin::signed(1),
-            description,
-            options,
-            voting_period
+            proposal_params(description, options, voting_period)
         ));
 
         // Try to vote with invalid option index
         assert_noop!(
-            VotingModule::vote(RuntimeOrigin::signed(2), 0, 2),
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 2, 10, Conviction::Locked1x),
             Error::<Test>::InvalidOptionIndex
         );
     });
@@ -217,26 +468,128 @@ fn vote_fails_when_already_voted() {
 
         assert_ok!(VotingModule::create_proposal(
             RuntimeOrigin::signed(1),
-            description,
-            options,
-            voting_period
+            proposal_params(description, options, voting_period)
         ));
 
         // Vote once
         assert_ok!(VotingModule::vote(
             RuntimeOrigin::signed(2),
             0,
-            0
+            0,
+            10,
+            Conviction::Locked1x
         ));
 
         // Try to vote again
         assert_noop!(
-            VotingModule::vote(RuntimeOrigin::signed(2), 0, 1),
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 1, 10, Conviction::Locked1x),
             Error::<Test>::AlreadyVoted
         );
     });
 }
 
+#[test]
+fn vote_fails_with_insufficient_balance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Create a proposal first
+        let description = b"Test proposal".to_vec();
+        let options = vec![b"Option A".to_vec(), b"Option B".to_vec()];
+        let voting_period = 100u64;
+
+        assert_ok!(VotingModule::create_proposal(
+            RuntimeOrigin::signed(1),
+            proposal_params(description, options, voting_period)
+        ));
+
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 0, Conviction::Locked1x),
+            Error::<Test>::ZeroVoteBalance
+        );
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 101, Conviction::Locked1x),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn unlock_releases_expired_locks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Create two proposals with different voting periods
+        assert_ok!(ProposalBuilder::new().voting_period(10).create());
+        assert_ok!(ProposalBuilder::new().create());
+
+        // The same funds back both votes, so the lock is the larger amount
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 80, Conviction::None));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 1, 1, 30, Conviction::None));
+        assert_eq!(Balances::usable_balance(2), 20);
+
+        // Nothing expires while the voting periods are running
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(3), 2));
+        assert_eq!(Balances::usable_balance(2), 20);
+
+        // Once the short proposal ends only the second vote stays locked
+        System::set_block_number(12);
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(3), 2));
+        assert_eq!(VotingModule::vote_locks(2).len(), 1);
+        assert_eq!(Balances::usable_balance(2), 70);
+        System::assert_last_event(Event::VotesUnlocked {
+            who: 2,
+            remaining: 30,
+        }.into());
+
+        // After both proposals end everything is released
+        System::set_block_number(102);
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(3), 2));
+        assert!(VotingModule::vote_locks(2).is_empty());
+        assert_eq!(Balances::usable_balance(2), 100);
+    });
+}
+
+#[test]
+fn conviction_multiplies_weight_and_extends_lock() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Create a proposal first
+        let description = b"Test proposal".to_vec();
+        let options = vec![b"Option A".to_vec(), b"Option B".to_vec()];
+        let voting_period = 100u64;
+
+        assert_ok!(VotingModule::create_proposal(
+            RuntimeOrigin::signed(1),
+            proposal_params(description, options, voting_period)
+        ));
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 50, Conviction::None));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 1, 50, Conviction::Locked3x));
+
+        // 0.1x and 3x weights are tallied
+        assert_eq!(VotingModule::votes(0, 2).unwrap().weight, 5);
+        assert_eq!(VotingModule::votes(0, 3).unwrap().weight, 150);
+        assert_eq!(VotingModule::vote_results(0, 0), 5);
+        assert_eq!(VotingModule::vote_results(0, 1), 150);
+
+        // Locked3x keeps the balance locked for 4 periods after the proposal ends
+        let unlock_block = 101 + 4 * VoteLockingPeriod::get();
+        assert_eq!(VotingModule::vote_locks(3)[0].locked_until, unlock_block);
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(2), 2));
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(3), 3));
+        assert_eq!(Balances::usable_balance(2), 100);
+        assert_eq!(Balances::usable_balance(3), 50);
+
+        System::set_block_number(unlock_block + 1);
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(3), 3));
+        assert_eq!(Balances::usable_balance(3), 100);
+    });
+}
+
 #[test]
 fn end_proposal_works() {
     new_test_ext().execute_with(|| {
@@ -249,15 +602,13 @@ fn end_proposal_works() {
 
         assert_ok!(VotingModule::create_proposal(
             RuntimeOrigin::signed(1),
-            description,
-            options,
-            voting_period
+            proposal_params(description, options, voting_period)
         ));
 
         // Cast some votes
-        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0));
-        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 0));
-        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(4), 0, 1));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 0, 20, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(4), 0, 1, 25, Conviction::Locked1x));
 
         // Move past the voting period
         System::set_block_number(102);
@@ -265,15 +616,169 @@ fn end_proposal_works() {
         // End the proposal
         assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
 
-        // Check that the proposal is no longer active
+        // Check that the proposal passed
         let proposal = VotingModule::proposals(0).unwrap();
-        assert_eq!(proposal.is_active, false);
+        assert_eq!(proposal.status, ProposalStatus::Passed);
 
         // Check that the event was emitted with the correct winning option
         System::assert_last_event(Event::ProposalEnded {
             proposal_id: 0,
-            winning_option: Some(0), // Option A won with 2 votes
+            status: ProposalStatus::Passed,
+            winning_option: Some(0), // Option A won with a weight of 30
+            winners: vec![0],
+            tallies: vec![30, 25],
+        }.into());
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_invalid_thresholds() {
+    new_test_ext().execute_with(|| {
+        // Quorum below the minimum
+        assert_noop!(
+            ProposalBuilder::new().quorum(MinQuorum::get() - 1).create(),
+            Error::<Test>::QuorumTooLow
+        );
+
+        // Approval threshold below the minimum
+        assert_noop!(
+            ProposalBuilder::new().approval_threshold(Perbill::from_percent(49)).create(),
+            Error::<Test>::ApprovalThresholdTooLow
+        );
+    });
+}
+
+#[test]
+fn end_proposal_without_votes_has_no_quorum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .end();
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::NoQuorum);
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::NoQuorum,
+            winning_option: None,
+            winners: vec![],
+            tallies: vec![0, 0, 0],
+        }.into());
+    });
+}
+
+#[test]
+fn end_proposal_below_quorum_has_no_quorum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .quorum(50)
+            .votes(&[(2, 0, 30), (3, 1, 10)])
+            .end();
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::NoQuorum);
+    });
+}
+
+#[test]
+fn end_proposal_with_tied_options_is_tied() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .votes(&[(2, 0, 20), (3, 2, 20)])
+            .end();
+
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Tied,
+            winning_option: None,
+            winners: vec![],
+            tallies: vec![20, 0, 20],
+        }.into());
+    });
+}
+
+#[test]
+fn end_proposal_below_approval_threshold_is_rejected() {
+    new_test_ext().execute_with(|| {
+        // Option A leads with 60% of the weight but a two-thirds majority is required
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .approval_threshold(Perbill::from_percent(66))
+            .votes(&[(2, 0, 60), (3, 1, 40)])
+            .end();
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Rejected);
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Rejected,
+            winning_option: None,
+            winners: vec![],
+            tallies: vec![60, 40, 0],
+        }.into());
+    });
+}
+
+#[test]
+fn proposals_are_finalized_on_initialize() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Create a proposal
+        let description = b"Test proposal".to_vec();
+        let options = vec![b"Option A".to_vec(), b"Option B".to_vec()];
+        let voting_period = 100u64;
+
+        assert_ok!(VotingModule::create_proposal(
+            RuntimeOrigin::signed(1),
+            proposal_params(description, options, voting_period)
+        ));
+        assert_eq!(VotingModule::proposals_ending_at(101).into_inner(), vec![0]);
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 1, 10, Conviction::Locked1x));
+
+        // Nothing happens while the voting period is still running
+        System::set_block_number(101);
+        VotingModule::on_initialize(101);
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Active);
+
+        // The proposal is closed in the first block after its end block
+        System::set_block_number(102);
+        VotingModule::on_initialize(102);
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Passed);
+        assert!(VotingModule::proposals_ending_at(101).is_empty());
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Passed,
+            winning_option: Some(1),
+            winners: vec![1],
+            tallies: vec![0, 10],
         }.into());
+
+        // Ending it manually is no longer possible
+        assert_noop!(
+            VotingModule::end_proposal(RuntimeOrigin::signed(5), 0),
+            Error::<Test>::ProposalNotActive
+        );
+    });
+}
+
+#[test]
+fn create_proposal_fails_when_too_many_proposals_end_in_same_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for _ in 0..MaxProposalsEndingPerBlock::get() {
+            assert_ok!(ProposalBuilder::new().create());
+        }
+
+        assert_noop!(
+            ProposalBuilder::new().create(),
+            Error::<Test>::TooManyProposalsEndingAt
+        );
     });
 }
 
@@ -289,17 +794,18 @@ fn cancel_proposal_works() {
 
         assert_ok!(VotingModule::create_proposal(
             RuntimeOrigin::signed(1),
-            description,
-            options,
-            voting_period
+            proposal_params(description, options, voting_period)
         ));
 
         // Cancel the proposal
         assert_ok!(VotingModule::cancel_proposal(RuntimeOrigin::signed(1), 0));
 
-        // Check that the proposal is no longer active
+        // Check that the proposal is cancelled
         let proposal = VotingModule::proposals(0).unwrap();
-        assert_eq!(proposal.is_active, false);
+        assert_eq!(proposal.status, ProposalStatus::Cancelled);
+
+        // Check that the proposal is no longer scheduled to end
+        assert!(VotingModule::proposals_ending_at(101).is_empty());
 
         // Check that the event was emitted
         System::assert_last_event(Event::ProposalCancelled {
@@ -320,9 +826,7 @@ fn cancel_proposal_fails_when_not_proposer() {
 
         assert_ok!(VotingModule::create_proposal(
             RuntimeOrigin::signed(1),
-            description,
-            options,
-            voting_period
+            proposal_params(description, options, voting_period)
         ));
 
         // Try to cancel by someone else
@@ -331,4 +835,1602 @@ fn cancel_proposal_fails_when_not_proposer() {
             Error::<Test>::NotProposer
         );
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn passed_proposal_call_is_dispatched_after_enactment_delay() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let call = RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+            who: 20,
+            new_free: 50,
+        });
+        assert_ok!(ProposalBuilder::new()
+            .call(call)
+            .quorum(VotingModule::enactment_quorum())
+            .create());
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 100, Conviction::Locked3x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 0, 100, Conviction::Locked3x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(4), 0, 1, 10, Conviction::Locked1x));
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        System::assert_last_event(Event::ProposalEnactmentScheduled {
+            proposal_id: 0,
+            when: 102 + EnactmentDelay::get(),
+        }.into());
+
+        Scheduler::on_initialize(106);
+        assert_eq!(Balances::free_balance(20), 0);
+
+        Scheduler::on_initialize(107);
+        assert_eq!(Balances::free_balance(20), 50);
+    });
+}
+
+#[test]
+fn rejected_proposal_call_is_not_scheduled() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let call = RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+            who: 20,
+            new_free: 50,
+        });
+        assert_ok!(ProposalBuilder::new()
+            .call(call)
+            .quorum(VotingModule::enactment_quorum())
+            .create());
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::NoQuorum);
+
+        Scheduler::on_initialize(107);
+        assert_eq!(Balances::free_balance(20), 0);
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_call_too_long() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let remark = |len: u32| RuntimeCall::System(frame_system::Call::remark {
+            remark: vec![0; len as usize],
+        });
+        let long_call = remark(MaxCallLength::get());
+        assert!(long_call.encoded_size() as u32 > MaxCallLength::get());
+
+        assert_noop!(
+            ProposalBuilder::new()
+                .call(long_call)
+                .quorum(VotingModule::enactment_quorum())
+                .create(),
+            Error::<Test>::CallTooLong
+        );
+        assert_ok!(ProposalBuilder::new()
+            .call(remark(MaxCallLength::get() - 16))
+            .quorum(VotingModule::enactment_quorum())
+            .create());
+
+        // The weight grows with the length of the call
+        assert!(
+            <() as WeightInfo>::create_proposal(0, MaxCallLength::get()).ref_time() >
+                <() as WeightInfo>::create_proposal(0, 0).ref_time()
+        );
+    });
+}
+
+#[test]
+fn proposal_call_cannot_be_passed_by_proposer_alone() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let call = RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+            who: 1,
+            new_free: 1_000,
+        });
+
+        // Calls need a quorum and voting period at the enactment floor
+        assert_noop!(
+            ProposalBuilder::new().call(call.clone()).create(),
+            Error::<Test>::QuorumTooLow
+        );
+        assert_noop!(
+            ProposalBuilder::new()
+                .call(call.clone())
+                .quorum(VotingModule::enactment_quorum())
+                .voting_period(MinEnactmentPeriod::get() - 1)
+                .create(),
+            Error::<Test>::InvalidVotingPeriod
+        );
+        assert_eq!(VotingModule::enactment_quorum(), 600);
+
+        // Even at the highest conviction the proposer cannot reach the quorum alone
+        assert_ok!(ProposalBuilder::new()
+            .call(call)
+            .quorum(VotingModule::enactment_quorum())
+            .create());
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(1), 0, 0, 95, Conviction::Locked6x));
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::NoQuorum);
+        assert!(VotingModule::enactment_task(0).is_none());
+        Scheduler::on_initialize(107);
+        assert_eq!(Balances::free_balance(1), 100);
+    });
+}
+
+#[test]
+fn delegated_power_is_counted_towards_delegate_vote() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(3),
+            2,
+            DelegationScope::All,
+            30,
+            Conviction::Locked2x
+        ));
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(4),
+            2,
+            DelegationScope::Proposal(0),
+            20,
+            Conviction::Locked1x
+        ));
+        assert_eq!(Balances::usable_balance(3), 70);
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 1, 10, Conviction::Locked1x));
+
+        let vote = VotingModule::votes(0, 2).unwrap();
+        assert_eq!(vote.weight, 10);
+        assert_eq!(vote.delegated, 80);
+        assert_eq!(VotingModule::vote_results(0, 1), 90);
+        assert_eq!(VotingModule::delegated_votes(0, 3), Some((2, 60)));
+        System::assert_last_event(Event::VoteCast {
+            proposal_id: 0,
+            voter: 2,
+            option_index: 1,
+            conviction: Conviction::Locked1x,
+            weight: 10,
+            delegated: 80,
+        }.into());
+    });
+}
+
+#[test]
+fn delegation_chains_are_followed_up_to_max_depth() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        // 5 -> 4 -> 3 -> 2, but only MaxDelegationDepth levels are followed
+        for (who, to) in [(3, 2), (4, 3), (5, 4)] {
+            assert_ok!(VotingModule::delegate(
+                RuntimeOrigin::signed(who),
+                to,
+                DelegationScope::All,
+                10,
+                Conviction::Locked1x
+            ));
+        }
+        assert_eq!(MaxDelegationDepth::get(), 2);
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x));
+        assert_eq!(VotingModule::votes(0, 2).unwrap().delegated, 20);
+        assert_eq!(VotingModule::vote_results(0, 0), 30);
+        assert_eq!(VotingModule::delegated_votes(0, 5), None);
+    });
+}
+
+#[test]
+fn direct_vote_overrides_delegation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(3),
+            2,
+            DelegationScope::All,
+            30,
+            Conviction::Locked1x
+        ));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x));
+        assert_eq!(VotingModule::vote_results(0, 0), 40);
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 1, 30, Conviction::Locked1x));
+        assert_eq!(VotingModule::votes(0, 2).unwrap().delegated, 0);
+        assert_eq!(VotingModule::vote_results(0, 0), 10);
+        assert_eq!(VotingModule::vote_results(0, 1), 30);
+        assert_eq!(VotingModule::delegated_votes(0, 3), None);
+    });
+}
+
+#[test]
+fn delegate_fails_on_cycle() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            VotingModule::delegate(RuntimeOrigin::signed(2), 2, DelegationScope::All, 10, Conviction::None),
+            Error::<Test>::SelfDelegation
+        );
+
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(2),
+            3,
+            DelegationScope::All,
+            10,
+            Conviction::None
+        ));
+        assert_noop!(
+            VotingModule::delegate(RuntimeOrigin::signed(3), 2, DelegationScope::All, 10, Conviction::None),
+            Error::<Test>::DelegationCycle
+        );
+        assert_noop!(
+            VotingModule::delegate(RuntimeOrigin::signed(2), 4, DelegationScope::All, 10, Conviction::None),
+            Error::<Test>::AlreadyDelegating
+        );
+    });
+}
+
+#[test]
+fn undelegate_keeps_balance_locked() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            VotingModule::undelegate(RuntimeOrigin::signed(3), DelegationScope::All),
+            Error::<Test>::NotDelegating
+        );
+
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(3),
+            2,
+            DelegationScope::All,
+            30,
+            Conviction::None
+        ));
+        assert_ok!(VotingModule::undelegate(RuntimeOrigin::signed(3), DelegationScope::All));
+        System::assert_last_event(Event::Undelegated { who: 3, scope: DelegationScope::All }.into());
+
+        assert!(VotingModule::delegators(2, DelegationScope::All).is_empty());
+        let locks = VotingModule::vote_locks(3);
+        assert_eq!(locks[0].proposal_id, None);
+        let locked_until = 1 + MaxVotingPeriod::get() + RevealPeriod::get();
+        assert_eq!(locks[0].locked_until, locked_until);
+
+        // Delegated power may back a secret ballot revealed at the end of the reveal window
+        System::set_block_number(1 + MaxVotingPeriod::get());
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(3), 3));
+        assert_eq!(Balances::usable_balance(3), 70);
+
+        System::set_block_number(locked_until + 1);
+        assert_ok!(VotingModule::unlock(RuntimeOrigin::signed(3), 3));
+        assert_eq!(Balances::usable_balance(3), 100);
+    });
+}
+
+fn commitment(option_index: u32, salt: [u8; 32]) -> H256 {
+    BlakeTwo256::hash_of(&(option_index, salt))
+}
+
+#[test]
+fn secret_ballot_is_counted_when_revealed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().secret_ballot().create());
+
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 40, Conviction::Locked1x),
+            Error::<Test>::SecretBallot
+        );
+        assert_ok!(VotingModule::commit_vote(
+            RuntimeOrigin::signed(2),
+            0,
+            commitment(1, [2; 32]),
+            40,
+            Conviction::Locked1x
+        ));
+        System::assert_last_event(Event::VoteCommitted { proposal_id: 0, voter: 2 }.into());
+        assert_eq!(Balances::reserved_balance(2), CommitDeposit::get());
+        assert_eq!(VotingModule::vote_results(0, 1), 0);
+
+        // Reveals are only accepted after the voting period
+        assert_noop!(
+            VotingModule::reveal_vote(RuntimeOrigin::signed(2), 0, 1, [2; 32]),
+            Error::<Test>::RevealPeriodNotStarted
+        );
+
+        System::set_block_number(102);
+        assert_noop!(
+            VotingModule::reveal_vote(RuntimeOrigin::signed(2), 0, 0, [2; 32]),
+            Error::<Test>::InvalidReveal
+        );
+        assert_ok!(VotingModule::reveal_vote(RuntimeOrigin::signed(2), 0, 1, [2; 32]));
+        assert_eq!(VotingModule::vote_results(0, 1), 40);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert!(VotingModule::commitments(0, 2).is_none());
+
+        // The proposal closes at the end of the reveal window
+        assert_noop!(
+            VotingModule::end_proposal(RuntimeOrigin::signed(5), 0),
+            Error::<Test>::VotingPeriodEnded
+        );
+        let closing_block = 101 + RevealPeriod::get();
+        VotingModule::on_initialize(closing_block + 1);
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Passed);
+    });
+}
+
+#[test]
+fn unrevealed_secret_ballot_is_excluded_and_slashed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().secret_ballot().create());
+
+        assert_ok!(VotingModule::commit_vote(
+            RuntimeOrigin::signed(2),
+            0,
+            commitment(0, [2; 32]),
+            40,
+            Conviction::None
+        ));
+        assert_ok!(VotingModule::commit_vote(
+            RuntimeOrigin::signed(3),
+            0,
+            commitment(1, [3; 32]),
+            20,
+            Conviction::Locked1x
+        ));
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::reveal_vote(RuntimeOrigin::signed(3), 0, 1, [3; 32]));
+
+        let closing_block = 101 + RevealPeriod::get();
+        assert_noop!(
+            VotingModule::reap_commitment(RuntimeOrigin::signed(5), 0, 2),
+            Error::<Test>::RevealPeriodNotOver
+        );
+        System::set_block_number(closing_block + 1);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        assert_eq!(VotingModule::get_vote_results(0), vec![0, 20]);
+
+        assert_ok!(VotingModule::reap_commitment(RuntimeOrigin::signed(5), 0, 2));
+        System::assert_last_event(Event::CommitmentReaped {
+            proposal_id: 0,
+            voter: 2,
+            slashed: CommitDeposit::get(),
+        }.into());
+        assert_eq!(Balances::free_balance(2), 100 - CommitDeposit::get());
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+#[test]
+fn vote_fails_with_wrong_voting_mode() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .mode(VotingMode::Approval)
+            .create());
+
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x),
+            Error::<Test>::WrongVotingMode
+        );
+        assert_noop!(
+            VotingModule::vote_ranked(RuntimeOrigin::signed(2), 0, vec![0], 10, Conviction::Locked1x),
+            Error::<Test>::WrongVotingMode
+        );
+        assert_noop!(
+            VotingModule::vote_approval(RuntimeOrigin::signed(2), 0, vec![], 10, Conviction::Locked1x),
+            Error::<Test>::EmptyBallot
+        );
+        assert_noop!(
+            VotingModule::vote_approval(RuntimeOrigin::signed(2), 0, vec![1, 1], 10, Conviction::Locked1x),
+            Error::<Test>::DuplicateOption
+        );
+        assert_noop!(
+            ProposalBuilder::new()
+                .secret_ballot()
+                .mode(VotingMode::RankedChoice)
+                .create(),
+            Error::<Test>::SecretBallotNotSupported
+        );
+    });
+}
+
+#[test]
+fn approval_vote_counts_weight_for_every_approved_option() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .mode(VotingMode::Approval)
+            .create());
+
+        assert_ok!(VotingModule::vote_approval(
+            RuntimeOrigin::signed(2),
+            0,
+            vec![0, 2],
+            30,
+            Conviction::Locked1x
+        ));
+        assert_ok!(VotingModule::vote_approval(
+            RuntimeOrigin::signed(3),
+            0,
+            vec![2],
+            20,
+            Conviction::Locked1x
+        ));
+        assert_eq!(VotingModule::get_vote_results(0), vec![30, 0, 50]);
+        assert_eq!(VotingModule::turnout(0), 50);
+        assert_eq!(VotingModule::ballots(0, 2).unwrap().into_inner(), vec![0, 2]);
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Passed,
+            winning_option: Some(2),
+            winners: vec![2],
+            tallies: vec![30, 0, 50],
+        }.into());
+    });
+}
+
+#[test]
+fn ranked_choice_is_decided_by_instant_runoff() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .mode(VotingMode::RankedChoice)
+            .create());
+
+        // A leads on first preferences, but C's voters prefer B over A
+        assert_ok!(VotingModule::vote_ranked(RuntimeOrigin::signed(2), 0, vec![0], 40, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote_ranked(RuntimeOrigin::signed(3), 0, vec![1, 0], 35, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote_ranked(RuntimeOrigin::signed(4), 0, vec![2, 1], 25, Conviction::Locked1x));
+        assert_eq!(VotingModule::get_vote_results(0), vec![40, 35, 25]);
+        assert_eq!(VotingModule::ranked_ballots(0).len(), 3);
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Passed,
+            winning_option: Some(1),
+            winners: vec![1],
+            tallies: vec![40, 60, 0],
+        }.into());
+    });
+}
+
+#[test]
+fn ranked_choice_fails_with_too_many_rankings() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .mode(VotingMode::RankedChoice)
+            .create());
+
+        let rankings = [vec![0], vec![1], vec![2], vec![0, 1]];
+        for (i, ranking) in rankings.iter().enumerate() {
+            assert_ok!(VotingModule::vote_ranked(
+                RuntimeOrigin::signed(2 + i as u64),
+                0,
+                ranking.clone(),
+                10,
+                Conviction::Locked1x
+            ));
+        }
+        assert_eq!(MaxRankings::get(), 4);
+
+        // Identical rankings share a slot
+        assert_ok!(VotingModule::vote_ranked(RuntimeOrigin::signed(6), 0, vec![0], 10, Conviction::Locked1x));
+        assert_noop!(
+            VotingModule::vote_ranked(RuntimeOrigin::signed(7), 0, vec![1, 0], 10, Conviction::Locked1x),
+            Error::<Test>::TooManyRankings
+        );
+    });
+}
+
+#[test]
+fn active_proposals_are_paginated() {
+    new_test_ext().execute_with(|| {
+        for block in 1..=4 {
+            System::set_block_number(block);
+            assert_ok!(ProposalBuilder::new().create());
+        }
+        assert_ok!(VotingModule::cancel_proposal(RuntimeOrigin::signed(1), 1));
+
+        let ids = |start, limit| {
+            VotingModule::active_proposals(start, limit)
+                .into_iter()
+                .map(|(proposal_id, _)| proposal_id)
+                .collect::<Vec<_>>()
+        };
+        // Pages cover ID ranges, so the cancelled proposal leaves the first page short
+        assert_eq!(ids(0, 2), vec![0]);
+        assert_eq!(ids(2, 2), vec![2, 3]);
+        assert_eq!(ids(3, 10), vec![3]);
+        assert_eq!(ids(4, 2), Vec::<u32>::new());
+        assert_eq!(ids(u32::MAX, 2), Vec::<u32>::new());
+    });
+}
+
+#[test]
+fn migration_to_v1_bounds_stored_proposals() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<VotingModule>();
+        System::set_block_number(20);
+        let old_proposal = |options: Vec<Vec<u8>>, end_block, is_active| v0::OldProposalInfo {
+            proposer: 1,
+            description: b"Old proposal".to_vec(),
+            options,
+            start_block: 1,
+            end_block,
+            is_active,
+        };
+        let long_option = vec![b'x'; (MaxOptionLength::get() + 8) as usize];
+        let options = vec![b"Option A".to_vec(), long_option];
+        v0::Proposals::<Test>::insert(0, old_proposal(options.clone(), 30, true));
+        v0::Proposals::<Test>::insert(1, old_proposal(options.clone(), 11, false));
+        v0::Proposals::<Test>::insert(2, old_proposal(options.clone(), 25, false));
+        v0::Proposals::<Test>::insert(3, old_proposal(options, 15, true));
+        v0::Votes::<Test>::insert(0, 2, OldVoteInfo { option_index: 0, weight: 1 });
+        v0::Votes::<Test>::insert(0, 3, OldVoteInfo { option_index: 0, weight: 1 });
+        v0::VoteResults::<Test>::insert(0, 0, 2);
+        v0::VoteResults::<Test>::insert(1, 0, 1);
+        v0::VoteResults::<Test>::insert(1, 1, 2);
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(VotingModule::on_chain_storage_version(), 1);
+        let proposal = v1::Proposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.description, b"Old proposal".to_vec());
+        assert_eq!(proposal.options[0], b"Option A".to_vec());
+        assert_eq!(proposal.options[1].len() as u32, MaxOptionLength::get());
+        assert_eq!(proposal.status, ProposalStatus::Active);
+        assert_eq!(proposal.quorum, 0);
+        // Closed after its end block with a leading option, closed early
+        assert_eq!(v1::Proposals::<Test>::get(1).unwrap().status, ProposalStatus::Passed);
+        assert_eq!(v1::Proposals::<Test>::get(2).unwrap().status, ProposalStatus::Cancelled);
+
+        // Votes keep their count as weight, without a locked balance
+        let vote = VotingModule::votes(0, 2).unwrap();
+        assert_eq!((vote.option_index, vote.balance, vote.weight), (0, 0, 1));
+        assert_eq!(VotingModule::vote_results(0, 0), 2);
+        assert_eq!(VotingModule::vote_results(1, 1), 2);
+        assert_eq!(VotingModule::turnout(0), 2);
+        assert_eq!(VotingModule::turnout(1), 3);
+
+        // Active proposals are indexed by their end block, or the current block once it passed
+        assert_eq!(VotingModule::proposals_ending_at(30).to_vec(), vec![0]);
+        assert_eq!(VotingModule::proposals_ending_at(20).to_vec(), vec![3]);
+
+        // The migrated proposals end on their own, decided by their migrated votes
+        MigrateToV2::<Test>::on_runtime_upgrade();
+        System::set_block_number(21);
+        VotingModule::on_initialize(21);
+        assert_eq!(VotingModule::proposals(3).unwrap().status, ProposalStatus::NoQuorum);
+        System::set_block_number(31);
+        VotingModule::on_initialize(31);
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Passed);
+    });
+}
+
+#[test]
+fn migration_to_v2_adds_empty_deposit() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<VotingModule>();
+        let proposal: v1::OldProposalInfoOf<Test> = OldProposalInfo {
+            proposer: 1,
+            description: b"Old proposal".to_vec().try_into().unwrap(),
+            options: vec![b"Option A".to_vec().try_into().unwrap()].try_into().unwrap(),
+            call: None,
+            start_block: 1,
+            end_block: 11,
+            quorum: 10,
+            approval_threshold: Perbill::from_percent(50),
+            status: ProposalStatus::Active,
+            secret_ballot: false,
+            mode: VotingMode::SingleChoice,
+        };
+        v1::Proposals::<Test>::insert(0, proposal);
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(VotingModule::on_chain_storage_version(), 2);
+        let proposal = VotingModule::proposals(0).unwrap();
+        assert_eq!(proposal.description, b"Old proposal".to_vec());
+        assert_eq!(proposal.deposit, 0);
+
+        // Ending the migrated proposal has no deposit to settle
+        System::set_block_number(12);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn members_only_proposal_rejects_non_members() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().eligibility(EligibilityRule::Members).create());
+        assert_eq!(VotingModule::eligibility_rule(0), Some(EligibilityRule::Members));
+
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(4), 0, 0, 10, Conviction::Locked1x),
+            Error::<Test>::NotEligible
+        );
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x));
+    });
+}
+
+#[test]
+fn asset_gated_proposal_requires_min_balance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .eligibility(EligibilityRule::AssetHolder { asset_id: 0, min_balance: 50 })
+            .create());
+
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(4), 0, 0, 10, Conviction::Locked1x),
+            Error::<Test>::NotEligible
+        );
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(5), 0, 0, 10, Conviction::Locked1x));
+    });
+}
+
+#[test]
+fn allowlisted_proposal_accepts_listed_accounts_only() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .eligibility(EligibilityRule::Allowlist(vec![2, 7].try_into().unwrap()))
+            .create());
+
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(3), 0, 0, 10, Conviction::Locked1x),
+            Error::<Test>::NotEligible
+        );
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(7), 0, 0, 10, Conviction::Locked1x));
+    });
+}
+
+#[test]
+fn open_proposal_stores_no_eligibility_rule() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().eligibility(EligibilityRule::Open).create());
+
+        assert_eq!(VotingModule::eligibility_rule(0), None);
+        assert!(VotingModule::is_eligible(&9, 0));
+    });
+}
+
+#[test]
+fn ineligible_delegations_are_not_counted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().eligibility(EligibilityRule::Members).create());
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(5),
+            2,
+            DelegationScope::All,
+            30,
+            Conviction::Locked1x
+        ));
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(3),
+            2,
+            DelegationScope::All,
+            20,
+            Conviction::Locked1x
+        ));
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x));
+
+        assert_eq!(VotingModule::vote_results(0, 0), 30);
+    });
+}
+
+#[test]
+fn ended_proposal_votes_are_removed_on_idle() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .votes(&[(2, 0, 30), (3, 1, 10), (4, 0, 20)])
+            .end();
+        assert!(VotingModule::cleanup_queue(0).is_some());
+
+        VotingModule::on_idle(102, Weight::MAX);
+
+        assert!(VotingModule::cleanup_queue(0).is_none());
+        assert!(VotingModule::votes(0, 2).is_none());
+        assert_eq!(VotingModule::vote_results(0, 0), 0);
+        assert_eq!(VotingModule::turnout(0), 0);
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Passed);
+        System::assert_last_event(Event::ProposalArchived {
+            proposal_id: 0,
+            status: ProposalStatus::Passed,
+            winning_option: Some(0),
+            turnout: 60,
+            votes: 3,
+        }.into());
+    });
+}
+
+#[test]
+fn archive_removes_veto_reason_and_waits_for_enactment() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .call(RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+                who: 20,
+                new_free: 50,
+            }))
+            .quorum(VotingModule::enactment_quorum())
+            .votes(&(2..=7).map(|voter| (voter, 0, 100)).collect::<Vec<_>>())
+            .end();
+        assert_ok!(ProposalBuilder::new().create());
+        assert_ok!(VotingModule::veto_proposal(RuntimeOrigin::root(), 1, b"Spam".to_vec()));
+
+        VotingModule::on_idle(102, Weight::MAX);
+
+        assert!(VotingModule::cleanup_queue(1).is_none());
+        assert!(VotingModule::veto_reason(1).is_none());
+        // The passed call can still be vetoed, so its proposal is kept until it is dispatched
+        assert!(VotingModule::cleanup_queue(0).is_some());
+        assert!(VotingModule::enactment_task(0).is_some());
+
+        Scheduler::on_initialize(107);
+        VotingModule::on_idle(107, Weight::MAX);
+
+        assert!(VotingModule::cleanup_queue(0).is_none());
+        assert!(VotingModule::enactment_task(0).is_none());
+    });
+}
+
+#[test]
+fn cleanup_resumes_from_cursor_when_out_of_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .options(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+            .votes(&[(2, 0, 30), (3, 1, 10), (4, 0, 20)])
+            .end();
+
+        // Enough weight to remove two votes
+        let per_key = <() as WeightInfo>::clear_votes(1) - <() as WeightInfo>::clear_votes(0);
+        let weight = <() as WeightInfo>::clear_votes(0) +
+            <() as WeightInfo>::archive_proposal() +
+            per_key.saturating_mul(2);
+        VotingModule::on_idle(102, weight);
+
+        let task = VotingModule::cleanup_queue(0).unwrap();
+        assert_eq!(task.votes, 2);
+        assert!(task.cursor.is_some());
+
+        VotingModule::on_idle(103, Weight::MAX);
+
+        assert!(VotingModule::cleanup_queue(0).is_none());
+        assert_eq!(crate::Votes::<Test>::iter_prefix(0).count(), 0);
+        System::assert_last_event(Event::ProposalArchived {
+            proposal_id: 0,
+            status: ProposalStatus::Passed,
+            winning_option: Some(0),
+            turnout: 60,
+            votes: 3,
+        }.into());
+    });
+}
+
+#[test]
+fn cancelled_proposal_is_queued_for_cleanup() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x));
+        assert_ok!(VotingModule::cancel_proposal(RuntimeOrigin::signed(1), 0));
+
+        VotingModule::on_idle(2, Weight::MAX);
+
+        assert!(VotingModule::votes(0, 2).is_none());
+        System::assert_last_event(Event::ProposalArchived {
+            proposal_id: 0,
+            status: ProposalStatus::Cancelled,
+            winning_option: None,
+            turnout: 10,
+            votes: 1,
+        }.into());
+    });
+}
+
+/// Vote for `option_index` with 10 locked at 1x, signed by `voter`
+fn signed_vote(
+    voter: u64,
+    proposal_id: u32,
+    option_index: u32,
+    nonce: u32,
+) -> (u64, u32, u32, u64, Conviction, u32, TestSignature) {
+    let payload = (
+        SIGNED_VOTE_CONTEXT,
+        System::block_hash(0),
+        proposal_id,
+        option_index,
+        10u64,
+        Conviction::Locked1x,
+        nonce,
+    )
+        .encode();
+    let signature = TestSignature(voter, payload);
+    (voter, proposal_id, option_index, 10, Conviction::Locked1x, nonce, signature)
+}
+
+#[test]
+fn submit_signed_votes_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+        let votes = vec![signed_vote(2, 0, 0, 0), signed_vote(3, 0, 1, 0)];
+
+        assert_ok!(VotingModule::submit_signed_votes(RuntimeOrigin::signed(4), votes));
+
+        // Only the signed balance is locked, with the signed conviction
+        let vote = VotingModule::votes(0, 2).unwrap();
+        assert_eq!((vote.balance, vote.conviction, vote.weight), (10, Conviction::Locked1x, 10));
+        assert_eq!(Balances::usable_balance(2), 90);
+        assert!(VotingModule::votes(0, 3).is_some());
+        assert!(VotingModule::votes(0, 4).is_none());
+        assert_eq!(VotingModule::signed_vote_nonce(2), 1);
+        assert_eq!(VotingModule::signed_vote_nonce(3), 1);
+    });
+}
+
+#[test]
+fn submit_signed_votes_rejects_replayed_nonce() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+        assert_ok!(ProposalBuilder::new().create());
+        let vote = signed_vote(2, 0, 0, 0);
+        assert_ok!(VotingModule::submit_signed_votes(RuntimeOrigin::signed(4), vec![vote.clone()]));
+
+        assert_noop!(
+            VotingModule::submit_signed_votes(RuntimeOrigin::signed(4), vec![vote]),
+            Error::<Test>::InvalidNonce
+        );
+        assert_ok!(VotingModule::submit_signed_votes(
+            RuntimeOrigin::signed(4),
+            vec![signed_vote(2, 1, 0, 1)]
+        ));
+        assert_eq!(VotingModule::signed_vote_nonce(2), 2);
+    });
+}
+
+#[test]
+fn submit_signed_votes_rejects_invalid_signature() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        let vote = signed_vote(2, 0, 0, 0);
+        // Signed by another account
+        assert_noop!(
+            VotingModule::submit_signed_votes(
+                RuntimeOrigin::signed(4),
+                vec![(2, 0, 0, 10, Conviction::Locked1x, 0, signed_vote(3, 0, 0, 0).6)]
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // Signed for another option
+        assert_noop!(
+            VotingModule::submit_signed_votes(
+                RuntimeOrigin::signed(4),
+                vec![(2, 0, 1, 10, Conviction::Locked1x, 0, vote.6.clone())]
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // Relayed with a larger balance or a longer conviction than signed
+        assert_noop!(
+            VotingModule::submit_signed_votes(
+                RuntimeOrigin::signed(4),
+                vec![(2, 0, 0, 100, Conviction::Locked1x, 0, vote.6.clone())]
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            VotingModule::submit_signed_votes(
+                RuntimeOrigin::signed(4),
+                vec![(2, 0, 0, 10, Conviction::Locked6x, 0, vote.6)]
+            ),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn submit_signed_votes_fails_with_too_many_votes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+        let max: u32 = <Test as crate::Config>::MaxSignedVotes::get();
+        let votes = (1..=max as u64 + 1).map(|who| signed_vote(who, 0, 0, 0)).collect();
+
+        assert_noop!(
+            VotingModule::submit_signed_votes(RuntimeOrigin::signed(4), votes),
+            Error::<Test>::TooManySignedVotes
+        );
+    });
+}
+
+#[test]
+fn cancel_origin_can_cancel_any_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_ok!(VotingModule::cancel_proposal(RuntimeOrigin::root(), 0));
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Cancelled);
+        System::assert_last_event(Event::ProposalCancelled { proposal_id: 0 }.into());
+    });
+}
+
+#[test]
+fn veto_proposal_stops_active_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_ok!(VotingModule::veto_proposal(RuntimeOrigin::root(), 0, b"spam".to_vec()));
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Vetoed);
+        assert_eq!(VotingModule::veto_reason(0).unwrap().into_inner(), b"spam".to_vec());
+        assert!(VotingModule::proposals_ending_at(101).is_empty());
+        assert!(VotingModule::cleanup_queue(0).is_some());
+        System::assert_last_event(Event::ProposalVetoed {
+            proposal_id: 0,
+            reason: b"spam".to_vec(),
+        }.into());
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x),
+            Error::<Test>::ProposalNotActive
+        );
+    });
+}
+
+#[test]
+fn veto_proposal_cancels_scheduled_enactment() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .call(RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+                who: 20,
+                new_free: 50,
+            }))
+            .quorum(VotingModule::enactment_quorum())
+            .votes(&(2..=7).map(|voter| (voter, 0, 100)).collect::<Vec<_>>())
+            .end();
+        assert!(VotingModule::enactment_task(0).is_some());
+
+        assert_ok!(VotingModule::veto_proposal(RuntimeOrigin::root(), 0, b"unsafe".to_vec()));
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Vetoed);
+        assert!(VotingModule::enactment_task(0).is_none());
+        assert_eq!(VotingModule::cleanup_queue(0).unwrap().status, ProposalStatus::Vetoed);
+
+        Scheduler::on_initialize(107);
+        assert_eq!(Balances::free_balance(20), 0);
+    });
+}
+
+#[test]
+fn veto_proposal_fails_after_enactment() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .call(RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+                who: 20,
+                new_free: 50,
+            }))
+            .quorum(VotingModule::enactment_quorum())
+            .votes(&(2..=7).map(|voter| (voter, 0, 100)).collect::<Vec<_>>())
+            .end();
+        Scheduler::on_initialize(107);
+        assert_eq!(Balances::free_balance(20), 50);
+
+        assert_noop!(
+            VotingModule::veto_proposal(RuntimeOrigin::root(), 0, b"too late".to_vec()),
+            Error::<Test>::AlreadyEnacted
+        );
+    });
+}
+
+#[test]
+fn veto_proposal_fails_with_invalid_input() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_noop!(
+            VotingModule::veto_proposal(RuntimeOrigin::signed(1), 0, b"spam".to_vec()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        let max: u32 = <Test as crate::Config>::MaxVetoReasonLength::get();
+        assert_noop!(
+            VotingModule::veto_proposal(RuntimeOrigin::root(), 0, vec![0; max as usize + 1]),
+            Error::<Test>::VetoReasonTooLong
+        );
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        assert_noop!(
+            VotingModule::veto_proposal(RuntimeOrigin::root(), 0, b"spam".to_vec()),
+            Error::<Test>::CannotVeto
+        );
+    });
+}
+
+#[test]
+fn create_proposal_records_snapshot_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        ProposalBuilder::new().start_delay(3).create().unwrap();
+
+        assert_eq!(VotingModule::snapshot_block(0), Some(5));
+    });
+}
+
+#[test]
+fn transferred_balance_cannot_be_voted_twice() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        System::set_block_number(2);
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 40, Conviction::Locked1x));
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 11, 50));
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), 3, 50));
+
+        // Account 11 held nothing at the snapshot
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(11), 0, 0, 50, Conviction::Locked1x),
+            Error::<Test>::InsufficientSnapshotBalance
+        );
+        // Account 3 can only vote with the balance it held at the snapshot
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(3), 0, 0, 150, Conviction::Locked1x),
+            Error::<Test>::InsufficientSnapshotBalance
+        );
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 0, 100, Conviction::Locked1x));
+        assert_eq!(VotingModule::snapshot_balance(&3, 0), 100);
+    });
+}
+
+#[test]
+fn balance_checkpoints_record_history() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(2);
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 11, 10));
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 11, 10));
+        System::set_block_number(4);
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 11, 10));
+
+        // Only the balance before the first change in a block is recorded
+        assert_eq!(VotingModule::native_checkpoints(2).into_inner(), vec![(2, 100), (4, 80)]);
+        assert_eq!(VotingModule::balance_at(&2, 1), 100);
+        assert_eq!(VotingModule::balance_at(&2, 2), 100);
+        assert_eq!(VotingModule::balance_at(&2, 3), 80);
+        assert_eq!(VotingModule::balance_at(&2, 4), 80);
+        assert_eq!(VotingModule::balance_at(&2, 5), 70);
+        assert_eq!(VotingModule::balance_at(&11, 2), 0);
+    });
+}
+
+#[test]
+fn balance_checkpoints_are_bounded() {
+    new_test_ext().execute_with(|| {
+        // Account 2 receives one more in every block
+        let max: u32 = <Test as crate::Config>::MaxCheckpoints::get();
+        for block in 1..=max as u64 + 1 {
+            System::set_block_number(block);
+            assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), 2, 1));
+        }
+
+        // The two oldest checkpoints are merged into the lower balance
+        let checkpoints = VotingModule::native_checkpoints(2);
+        assert_eq!(checkpoints.len() as u32, max);
+        assert_eq!(checkpoints.first(), Some(&(2, 100)));
+        assert_eq!(VotingModule::balance_at(&2, 1), 100);
+        assert_eq!(VotingModule::balance_at(&2, 2), 100);
+        assert_eq!(VotingModule::balance_at(&2, 3), 102);
+    });
+}
+
+#[test]
+fn asset_eligibility_uses_snapshot_balance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .eligibility(EligibilityRule::AssetHolder { asset_id: 0, min_balance: 50 })
+            .create());
+
+        // Account 6 received its asset balance after the snapshot
+        System::set_block_number(2);
+        VotingModule::note_asset_balance_change(0, &6, 0);
+
+        assert_eq!(VotingModule::asset_balance_at(0, &6, 1), 0);
+        assert_noop!(
+            VotingModule::vote(RuntimeOrigin::signed(6), 0, 0, 10, Conviction::Locked1x),
+            Error::<Test>::NotEligible
+        );
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(5), 0, 0, 10, Conviction::Locked1x));
+    });
+}
+
+#[test]
+fn voter_history_lists_most_recent_votes_first() {
+    new_test_ext().execute_with(|| {
+        for block in 1..=3 {
+            System::set_block_number(block);
+            assert_ok!(ProposalBuilder::new().create());
+        }
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 1, 0, 10, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 10, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 2, 0, 10, Conviction::Locked1x));
+
+        assert_eq!(VotingModule::votes_by_account(2).into_inner(), vec![1, 0]);
+        assert_eq!(VotingModule::voter_history(&2, 0), vec![0, 1]);
+        assert_eq!(VotingModule::voter_history(&3, 0), vec![2]);
+        assert!(VotingModule::voter_history(&2, 1).is_empty());
+        assert!(VotingModule::voter_history(&4, 0).is_empty());
+    });
+}
+
+#[test]
+fn voter_history_forgets_oldest_votes() {
+    new_test_ext().execute_with(|| {
+        let max: u32 = <Test as crate::Config>::MaxVoterHistory::get();
+        for block in 1..=max as u64 + 1 {
+            System::set_block_number(block);
+            assert_ok!(ProposalBuilder::new().create());
+            let proposal_id = block as u32 - 1;
+            assert_ok!(VotingModule::vote(
+                RuntimeOrigin::signed(2),
+                proposal_id,
+                0,
+                10,
+                Conviction::Locked1x
+            ));
+        }
+
+        let history = VotingModule::votes_by_account(2);
+        assert_eq!(history.len() as u32, max);
+        assert_eq!(history.first(), Some(&1));
+        assert_eq!(history.last(), Some(&max));
+    });
+}
+
+#[test]
+fn proposal_reaching_quorum_opens_reward_pool() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&VotingModule::reward_pot(), 1_000);
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .votes(&[(1, 0, 20), (2, 0, 30), (3, 1, 10)])
+            .reward(ParticipationReward::get())
+            .end();
+
+        let expires_at = 102 + RewardClaimPeriod::get();
+        let pool = VotingModule::reward_pool(0).unwrap();
+        assert_eq!(pool.amount, ParticipationReward::get());
+        // The proposer's own vote is not rewarded
+        assert_eq!(pool.turnout, 40);
+        assert_noop!(
+            VotingModule::claim_reward(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::NoReward
+        );
+        assert_eq!(VotingModule::reward_funds(0), 0);
+        assert_eq!(VotingModule::reward_reserve(), 0);
+        assert_eq!(pool.expires_at, expires_at);
+        assert_eq!(VotingModule::outstanding_rewards(), ParticipationReward::get());
+        assert_eq!(VotingModule::rewards_expiring_at(expires_at).into_inner(), vec![0]);
+        // Votes are kept until the claim period is over
+        assert!(VotingModule::cleanup_queue(0).is_none());
+        System::assert_has_event(Event::RewardPoolOpened {
+            proposal_id: 0,
+            amount: ParticipationReward::get(),
+            expires_at,
+        }.into());
+    });
+}
+
+#[test]
+fn reward_pool_is_not_opened_when_too_many_expire_in_the_block() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&VotingModule::reward_pot(), 1_000);
+        System::set_block_number(1);
+        for proposal_id in 0..2 {
+            assert_ok!(ProposalBuilder::new().create());
+            fund_reward(proposal_id, ParticipationReward::get());
+            assert_ok!(VotingModule::vote(
+                RuntimeOrigin::signed(2),
+                proposal_id,
+                0,
+                30,
+                Conviction::Locked1x
+            ));
+        }
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 1));
+
+        let expires_at = 102 + RewardClaimPeriod::get();
+        assert_eq!(VotingModule::rewards_expiring_at(expires_at).into_inner(), vec![0]);
+        assert!(VotingModule::reward_pool(1).is_none());
+        assert!(VotingModule::cleanup_queue(1).is_some());
+        // The earmark of the proposal left without a pool stays in the reserve
+        assert_eq!(VotingModule::reward_reserve(), ParticipationReward::get());
+        System::assert_has_event(Event::RewardPoolsFull { proposal_id: 1, expires_at }.into());
+    });
+}
+
+#[test]
+fn self_voting_proposer_claims_nothing() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&VotingModule::reward_pot(), 1_000);
+        System::set_block_number(1);
+        ProposalBuilder::new().votes(&[(1, 0, 50)]).reward(ParticipationReward::get()).end();
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Passed);
+        assert!(VotingModule::reward_pool(0).is_none());
+        assert_noop!(
+            VotingModule::claim_reward(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::NoReward
+        );
+        // Neither the pot nor the earmark is paid out
+        assert_eq!(Balances::free_balance(VotingModule::reward_pot()), 1_100);
+        assert_eq!(VotingModule::reward_reserve(), ParticipationReward::get());
+    });
+}
+
+#[test]
+fn claim_reward_pays_pro_rata_to_weight() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&VotingModule::reward_pot(), 1_000);
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .votes(&[(2, 0, 30), (3, 1, 10)])
+            .reward(ParticipationReward::get())
+            .end();
+
+        assert_ok!(VotingModule::claim_reward(RuntimeOrigin::signed(2), 0));
+        assert_ok!(VotingModule::claim_reward(RuntimeOrigin::signed(3), 0));
+
+        assert_eq!(Balances::free_balance(2), 175);
+        assert_eq!(Balances::free_balance(3), 125);
+        assert_eq!(Balances::free_balance(VotingModule::reward_pot()), 1_000);
+        assert_eq!(VotingModule::reward_claim(0, 2), Some(75));
+        assert_eq!(VotingModule::reward_pool(0).unwrap().claimed, 100);
+        assert_eq!(VotingModule::outstanding_rewards(), 0);
+        System::assert_last_event(Event::RewardClaimed { proposal_id: 0, who: 3, amount: 25 }.into());
+    });
+}
+
+#[test]
+fn claim_reward_fails_with_invalid_claims() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&VotingModule::reward_pot(), 1_000);
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .votes(&[(2, 0, 30), (3, 1, 10)])
+            .reward(ParticipationReward::get())
+            .end();
+        assert_ok!(VotingModule::claim_reward(RuntimeOrigin::signed(2), 0));
+
+        assert_noop!(
+            VotingModule::claim_reward(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::RewardAlreadyClaimed
+        );
+        assert_noop!(
+            VotingModule::claim_reward(RuntimeOrigin::signed(4), 0),
+            Error::<Test>::NotVoted
+        );
+
+        // Proposals without quorum pay no reward
+        assert_ok!(ProposalBuilder::new().create());
+        System::set_block_number(203);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 1));
+        assert!(VotingModule::reward_pool(1).is_none());
+        assert_noop!(
+            VotingModule::claim_reward(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::NoReward
+        );
+    });
+}
+
+#[test]
+fn expired_reward_pool_is_cleaned_up() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&VotingModule::reward_pot(), 1_000);
+        System::set_block_number(1);
+        ProposalBuilder::new()
+            .votes(&[(2, 0, 30), (3, 1, 10)])
+            .reward(ParticipationReward::get())
+            .end();
+        assert_ok!(VotingModule::claim_reward(RuntimeOrigin::signed(2), 0));
+
+        let expires_at = 102 + RewardClaimPeriod::get();
+        System::set_block_number(expires_at);
+        VotingModule::on_initialize(expires_at);
+
+        assert!(VotingModule::reward_pool(0).is_none());
+        assert_eq!(VotingModule::outstanding_rewards(), 0);
+        assert_eq!(VotingModule::reward_reserve(), 25);
+        System::assert_last_event(Event::RewardPoolExpired { proposal_id: 0, unclaimed: 25 }.into());
+        assert_noop!(
+            VotingModule::claim_reward(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::NoReward
+        );
+
+        VotingModule::on_idle(expires_at, Weight::MAX);
+        assert!(VotingModule::votes(0, 2).is_none());
+        assert!(VotingModule::reward_claim(0, 2).is_none());
+    });
+}
+
+#[test]
+fn create_proposal_reserves_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_eq!(VotingModule::proposals(0).unwrap().deposit, ProposalDeposit::get());
+        assert_eq!(Balances::reserved_balance(1), ProposalDeposit::get());
+        assert_eq!(Balances::free_balance(1), 100 - ProposalDeposit::get());
+    });
+}
+
+#[test]
+fn create_proposal_fails_without_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ProposalBuilder::new().proposer(20).create(),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn deposit_is_returned_when_proposal_ends_with_support() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+        // Half of the quorum is enough to get the deposit back, even without quorum
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 5, Conviction::Locked1x));
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::NoQuorum);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        System::assert_has_event(Event::DepositReturned {
+            proposal_id: 0,
+            who: 1,
+            amount: ProposalDeposit::get(),
+        }.into());
+    });
+}
+
+#[test]
+fn deposit_is_slashed_when_proposal_lacks_support() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 0, 4, Conviction::Locked1x));
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100 - ProposalDeposit::get());
+        assert_eq!(Balances::free_balance(VotingModule::reward_pot()), ProposalDeposit::get());
+        assert_eq!(VotingModule::reward_reserve(), ProposalDeposit::get());
+        System::assert_has_event(Event::DepositSlashed {
+            proposal_id: 0,
+            who: 1,
+            amount: ProposalDeposit::get(),
+        }.into());
+    });
+}
+
+#[test]
+fn deposit_is_returned_when_proposal_is_cancelled() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_ok!(VotingModule::cancel_proposal(RuntimeOrigin::signed(1), 0));
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        System::assert_has_event(Event::DepositReturned {
+            proposal_id: 0,
+            who: 1,
+            amount: ProposalDeposit::get(),
+        }.into());
+    });
+}
+
+#[test]
+fn deposit_is_slashed_when_cancel_origin_cancels() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_ok!(VotingModule::cancel_proposal(RuntimeOrigin::root(), 0));
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100 - ProposalDeposit::get());
+        assert_eq!(VotingModule::reward_reserve(), ProposalDeposit::get());
+        System::assert_has_event(Event::DepositSlashed {
+            proposal_id: 0,
+            who: 1,
+            amount: ProposalDeposit::get(),
+        }.into());
+    });
+}
+
+#[test]
+fn deposit_is_slashed_when_proposal_is_vetoed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new().create());
+
+        assert_ok!(VotingModule::veto_proposal(RuntimeOrigin::root(), 0, b"Spam".to_vec()));
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100 - ProposalDeposit::get());
+        assert_eq!(Balances::free_balance(VotingModule::reward_pot()), ProposalDeposit::get());
+        System::assert_has_event(Event::DepositSlashed {
+            proposal_id: 0,
+            who: 1,
+            amount: ProposalDeposit::get(),
+        }.into());
+    });
+}
+
+#[test]
+fn election_elects_top_options() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .options(vec![b"Alice".to_vec(), b"Bob".to_vec(), b"Charlie".to_vec()])
+            .mode(VotingMode::Approval)
+            .seats(2)
+            .create());
+        assert_eq!(VotingModule::proposal_seats(0), Some(2));
+
+        assert_ok!(VotingModule::vote_approval(
+            RuntimeOrigin::signed(2), 0, vec![2, 1], 20, Conviction::Locked1x
+        ));
+        assert_ok!(VotingModule::vote_approval(
+            RuntimeOrigin::signed(3), 0, vec![1, 0], 10, Conviction::Locked1x
+        ));
+        assert_ok!(VotingModule::vote_approval(
+            RuntimeOrigin::signed(4), 0, vec![0], 10, Conviction::Locked1x
+        ));
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+
+        // Alice and Charlie are level, Charlie received his first vote earlier
+        assert_eq!(VotingModule::first_votes(0).into_inner(), vec![2, 1, 0]);
+        assert_eq!(VotingModule::proposals(0).unwrap().status, ProposalStatus::Passed);
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Passed,
+            winning_option: Some(1),
+            winners: vec![1, 2],
+            tallies: vec![20, 30, 20],
+        }.into());
+    });
+}
+
+#[test]
+fn election_below_approval_threshold_is_rejected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .options(vec![b"Alice".to_vec(), b"Bob".to_vec(), b"Charlie".to_vec()])
+            .seats(2)
+            .create());
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 2, 20, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 1, 10, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(4), 0, 0, 10, Conviction::Locked1x));
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+
+        // Bob fills the second seat with a quarter of the turnout, below the 50% threshold
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Rejected,
+            winning_option: None,
+            winners: vec![],
+            tallies: vec![10, 10, 20],
+        }.into());
+    });
+}
+
+#[test]
+fn election_without_enough_candidates_is_rejected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProposalBuilder::new()
+            .options(vec![b"Alice".to_vec(), b"Bob".to_vec(), b"Charlie".to_vec()])
+            .seats(3)
+            .create());
+
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(2), 0, 2, 20, Conviction::Locked1x));
+        assert_ok!(VotingModule::vote(RuntimeOrigin::signed(3), 0, 1, 10, Conviction::Locked1x));
+
+        System::set_block_number(102);
+        assert_ok!(VotingModule::end_proposal(RuntimeOrigin::signed(5), 0));
+
+        System::assert_last_event(Event::ProposalEnded {
+            proposal_id: 0,
+            status: ProposalStatus::Rejected,
+            winning_option: None,
+            winners: vec![],
+            tallies: vec![0, 10, 20],
+        }.into());
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_invalid_seats() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ProposalBuilder::new().seats(0).create(),
+            Error::<Test>::InvalidSeats
+        );
+        assert_noop!(
+            ProposalBuilder::new().seats(3).create(),
+            Error::<Test>::InvalidSeats
+        );
+        assert_noop!(
+            ProposalBuilder::new()
+                .mode(VotingMode::RankedChoice)
+                .seats(2)
+                .create(),
+            Error::<Test>::SeatsNotSupported
+        );
+
+        // Three single-choice seats cannot each reach half of the turnout
+        let options = vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()];
+        assert_noop!(
+            ProposalBuilder::new().options(options.clone()).seats(3).create(),
+            Error::<Test>::ThresholdUnreachable
+        );
+        assert_ok!(ProposalBuilder::new()
+            .options(options.clone())
+            .mode(VotingMode::Approval)
+            .seats(3)
+            .create());
+        assert_ok!(ProposalBuilder::new().options(options).seats(2).create());
+    });
+}
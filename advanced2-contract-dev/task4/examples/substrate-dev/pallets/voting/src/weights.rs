@@ -1,13 +1,12 @@
-//! Autogenerated weights for pallet_voting
+//! Weights for pallet_voting
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-06-16, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/production/substrate
+//! THESE WEIGHTS ARE ESTIMATES AND HAVE NOT BEEN BENCHMARKED. Every function lists the storage it
+//! touches. Proof sizes follow the `MaxEncodedLen` bounds of the node runtime, execution times are
+//! conservative guesses and nothing was measured. Regenerate this file with
+//! `scripts/benchmark.sh` before deploying.
+
+// Command to regenerate:
+// ./target/release/substrate-dev-node
 // benchmark
 // pallet
 // --chain=dev
@@ -15,11 +14,9 @@
 // --repeat=20
 // --pallet=pallet_voting
 // --extrinsic=*
-// --execution=wasm
 // --wasm-execution=compiled
 // --heap-pages=4096
 // --output=./pallets/voting/src/weights.rs
-// --template=./.maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -30,10 +27,29 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_voting.
 pub trait WeightInfo {
-    fn create_proposal() -> Weight;
-    fn vote() -> Weight;
+    fn create_proposal(a: u32, c: u32, ) -> Weight;
+    fn vote(d: u32, ) -> Weight;
     fn end_proposal() -> Weight;
     fn cancel_proposal() -> Weight;
+    fn unlock() -> Weight;
+    fn on_initialize(p: u32, ) -> Weight;
+    fn open_proposals(p: u32, ) -> Weight;
+    fn expire_reward_pools(p: u32, ) -> Weight;
+    fn delegate() -> Weight;
+    fn undelegate() -> Weight;
+    fn commit_vote() -> Weight;
+    fn reveal_vote(d: u32, ) -> Weight;
+    fn reap_commitment() -> Weight;
+    fn vote_approval(o: u32, d: u32, ) -> Weight;
+    fn vote_ranked(o: u32, d: u32, ) -> Weight;
+    fn instant_runoff(o: u32, r: u32, ) -> Weight;
+    fn submit_signed_votes(v: u32, d: u32, ) -> Weight;
+    fn veto_proposal() -> Weight;
+    fn claim_reward() -> Weight;
+    fn fund_reward() -> Weight;
+    fn clear_votes(k: u32, ) -> Weight;
+    fn archive_proposal() -> Weight;
+    fn note_balance_change() -> Weight;
 }
 
 /// Weights for pallet_voting using the Substrate node and recommended hardware.
@@ -41,88 +57,850 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Voting NextProposalId (r:1 w:1)
     /// Proof: Voting NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalsStartingAt (r:1 w:1)
+    /// Proof: Voting ProposalsStartingAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalsEndingAt (r:1 w:1)
+    /// Proof: Voting ProposalsEndingAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
     /// Storage: Voting Proposals (r:0 w:1)
-    /// Proof: Voting Proposals (max_values: None, max_size: Some(1000), added: 3475, mode: MaxEncodedLen)
-    fn create_proposal() -> Weight {
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalEligibility (r:0 w:1)
+    /// Proof: Voting ProposalEligibility (max_values: None, max_size: Some(8215), added: 10690, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalSnapshots (r:0 w:1)
+    /// Proof: Voting ProposalSnapshots (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalSeats (r:0 w:1)
+    /// Proof: Voting ProposalSeats (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+    /// Storage: Preimage StatusFor (r:1 w:1)
+    /// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+    /// Storage: Preimage PreimageFor (r:0 w:1)
+    /// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
+    /// The range of component `a` is `[0, 256]`.
+    /// The range of component `c` is `[0, 16384]`.
+    fn create_proposal(a: u32, c: u32, ) -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `76`
-        //  Estimated: `1489`
-        // Minimum execution time: 15_000_000 picoseconds.
-        Weight::from_parts(16_000_000, 1489)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(2_u64))
+        //  Estimated: `12050`
+        Weight::from_parts(24_000_000, 12050)
+            .saturating_add(Weight::from_parts(12_000, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
     }
 
     /// Storage: Voting Proposals (r:1 w:0)
-    /// Proof: Voting Proposals (max_values: None, max_size: Some(1000), added: 3475, mode: MaxEncodedLen)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
     /// Storage: System Number (r:1 w:0)
     /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
     /// Storage: Voting Votes (r:1 w:1)
-    /// Proof: Voting Votes (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
     /// Storage: Voting VoteResults (r:1 w:1)
-    /// Proof: Voting VoteResults (max_values: None, max_size: Some(8), added: 2483, mode: MaxEncodedLen)
-    fn vote() -> Weight {
+    /// Proof: Voting VoteResults (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: Voting VoteLocks (r:1 w:1)
+    /// Proof: Voting VoteLocks (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting DelegatedVotes (r:2 w:1)
+    /// Proof: Voting DelegatedVotes (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+    /// Storage: Voting Delegators (r:2 w:0)
+    /// Proof: Voting Delegators (max_values: None, max_size: Some(2119), added: 4594, mode: MaxEncodedLen)
+    /// Storage: Voting Delegations (r:1 w:0)
+    /// Proof: Voting Delegations (max_values: None, max_size: Some(1777), added: 4252, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalSnapshots (r:1 w:0)
+    /// Proof: Voting ProposalSnapshots (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+    /// Storage: Voting NativeCheckpoints (r:1 w:0)
+    /// Proof: Voting NativeCheckpoints (max_values: None, max_size: Some(1330), added: 3805, mode: MaxEncodedLen)
+    /// Storage: Voting VotesByAccount (r:1 w:1)
+    /// Proof: Voting VotesByAccount (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:1 w:1)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    /// The range of component `d` is `[0, 8]`.
+    fn vote(d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `200`
-        //  Estimated: `4465`
-        // Minimum execution time: 25_000_000 picoseconds.
-        Weight::from_parts(26_000_000, 4465)
-            .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(2_u64))
+        //  Estimated: `53617 + d * (4252 ±0)`
+        Weight::from_parts(48_000_000, 53617)
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
     }
 
     /// Storage: Voting Proposals (r:1 w:1)
-    /// Proof: Voting Proposals (max_values: None, max_size: Some(1000), added: 3475, mode: MaxEncodedLen)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
     /// Storage: System Number (r:1 w:0)
     /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
     /// Storage: Voting VoteResults (r:10 w:0)
-    /// Proof: Voting VoteResults (max_values: None, max_size: Some(8), added: 2483, mode: MaxEncodedLen)
+    /// Proof: Voting VoteResults (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: Voting Turnout (r:1 w:0)
+    /// Proof: Voting Turnout (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalSeats (r:1 w:0)
+    /// Proof: Voting ProposalSeats (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:1 w:0)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting RewardFunds (r:1 w:1)
+    /// Proof: Voting RewardFunds (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting RewardReserve (r:1 w:1)
+    /// Proof: Voting RewardReserve (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:1 w:0)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Balances TotalIssuance (r:1 w:0)
+    /// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting RewardsExpiringAt (r:1 w:1)
+    /// Proof: Voting RewardsExpiringAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+    /// Storage: Voting RewardPools (r:0 w:1)
+    /// Proof: Voting RewardPools (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+    /// Storage: Voting OutstandingRewards (r:1 w:1)
+    /// Proof: Voting OutstandingRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting CleanupQueue (r:0 w:1)
+    /// Proof: Voting CleanupQueue (max_values: None, max_size: Some(306), added: 2781, mode: MaxEncodedLen)
+    /// Storage: Scheduler Agenda (r:1 w:1)
+    /// Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    /// Storage: Scheduler Lookup (r:1 w:1)
+    /// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Voting EnactmentTasks (r:0 w:1)
+    /// Proof: Voting EnactmentTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+    /// Storage: Preimage StatusFor (r:1 w:1)
+    /// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+    /// Storage: Preimage PreimageFor (r:0 w:1)
+    /// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
     fn end_proposal() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `300`
-        //  Estimated: `25820`
-        // Minimum execution time: 35_000_000 picoseconds.
-        Weight::from_parts(36_000_000, 25820)
-            .saturating_add(T::DbWeight::get().reads(12_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+        //  Estimated: `73203`
+        Weight::from_parts(78_000_000, 73203)
+            .saturating_add(T::DbWeight::get().reads(26_u64))
+            .saturating_add(T::DbWeight::get().writes(14_u64))
     }
 
     /// Storage: Voting Proposals (r:1 w:1)
-    /// Proof: Voting Proposals (max_values: None, max_size: Some(1000), added: 3475, mode: MaxEncodedLen)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalsEndingAt (r:1 w:1)
+    /// Proof: Voting ProposalsEndingAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting RewardFunds (r:1 w:1)
+    /// Proof: Voting RewardFunds (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting RewardReserve (r:1 w:1)
+    /// Proof: Voting RewardReserve (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
     fn cancel_proposal() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `15600`
+        Weight::from_parts(28_000_000, 15600)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+
+    /// Storage: Voting Proposals (r:1 w:1)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: Voting EnactmentTasks (r:1 w:1)
+    /// Proof: Voting EnactmentTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+    /// Storage: Scheduler Lookup (r:1 w:1)
+    /// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Scheduler Agenda (r:1 w:1)
+    /// Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    /// Storage: Voting CleanupQueue (r:1 w:1)
+    /// Proof: Voting CleanupQueue (max_values: None, max_size: Some(306), added: 2781, mode: MaxEncodedLen)
+    /// Storage: Voting VetoReasons (r:0 w:1)
+    /// Proof: Voting VetoReasons (max_values: None, max_size: Some(278), added: 2753, mode: MaxEncodedLen)
+    /// Storage: Voting RewardFunds (r:1 w:1)
+    /// Proof: Voting RewardFunds (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting RewardReserve (r:1 w:1)
+    /// Proof: Voting RewardReserve (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    fn veto_proposal() -> Weight {
+        // Estimated, not benchmarked.
         // Proof Size summary in bytes:
-        //  Measured:  `200`
-        //  Estimated: `3465`
-        // Minimum execution time: 20_000_000 picoseconds.
-        Weight::from_parts(21_000_000, 3465)
+        //  Estimated: `31046`
+        Weight::from_parts(42_000_000, 31046)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+
+    /// Storage: Voting RewardPools (r:1 w:1)
+    /// Proof: Voting RewardPools (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+    /// Storage: Voting RewardClaims (r:1 w:1)
+    /// Proof: Voting RewardClaims (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:1 w:0)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Voting Proposals (r:1 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting NativeCheckpoints (r:2 w:2)
+    /// Proof: Voting NativeCheckpoints (max_values: None, max_size: Some(1330), added: 3805, mode: MaxEncodedLen)
+    /// Storage: Voting OutstandingRewards (r:1 w:1)
+    /// Proof: Voting OutstandingRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    fn claim_reward() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `28313`
+        Weight::from_parts(56_000_000, 28313)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+
+    /// Storage: Voting Proposals (r:1 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting NativeCheckpoints (r:2 w:2)
+    /// Proof: Voting NativeCheckpoints (max_values: None, max_size: Some(1330), added: 3805, mode: MaxEncodedLen)
+    /// Storage: Voting RewardFunds (r:1 w:1)
+    /// Proof: Voting RewardFunds (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    fn fund_reward() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `22606`
+        Weight::from_parts(42_000_000, 22606)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+
+    /// Storage: Voting VoteLocks (r:1 w:1)
+    /// Proof: Voting VoteLocks (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn unlock() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `11190`
+        Weight::from_parts(39_000_000, 11190)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Storage: Voting ProposalsEndingAt (r:1 w:1)
+    /// Proof: Voting ProposalsEndingAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+    /// Storage: Voting Proposals (r:50 w:50)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Voting VoteResults (r:500 w:0)
+    /// Proof: Voting VoteResults (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: Voting Turnout (r:50 w:0)
+    /// Proof: Voting Turnout (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalSeats (r:50 w:0)
+    /// Proof: Voting ProposalSeats (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:50 w:0)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    /// Storage: System Account (r:100 w:100)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting RewardFunds (r:50 w:50)
+    /// Proof: Voting RewardFunds (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting RewardReserve (r:50 w:50)
+    /// Proof: Voting RewardReserve (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:50 w:0)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Balances TotalIssuance (r:50 w:0)
+    /// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting RewardsExpiringAt (r:50 w:50)
+    /// Proof: Voting RewardsExpiringAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+    /// Storage: Voting RewardPools (r:0 w:50)
+    /// Proof: Voting RewardPools (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+    /// Storage: Voting OutstandingRewards (r:50 w:50)
+    /// Proof: Voting OutstandingRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting CleanupQueue (r:0 w:50)
+    /// Proof: Voting CleanupQueue (max_values: None, max_size: Some(306), added: 2781, mode: MaxEncodedLen)
+    /// Storage: Scheduler Agenda (r:50 w:50)
+    /// Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    /// Storage: Scheduler Lookup (r:50 w:50)
+    /// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Voting EnactmentTasks (r:0 w:50)
+    /// Proof: Voting EnactmentTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+    /// Storage: Preimage StatusFor (r:50 w:50)
+    /// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+    /// Storage: Preimage PreimageFor (r:0 w:50)
+    /// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
+    /// The range of component `p` is `[0, 50]`.
+    fn on_initialize(p: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `4185 + p * (71714 ±0)`
+        Weight::from_parts(6_000_000, 4185)
+            .saturating_add(Weight::from_parts(76_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((25_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((14_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 71714).saturating_mul(p.into()))
+    }
+
+    /// Storage: Voting ProposalsStartingAt (r:1 w:1)
+    /// Proof: Voting ProposalsStartingAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+    /// Storage: Voting Proposals (r:50 w:50)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// The range of component `p` is `[0, 50]`.
+    fn open_proposals(p: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `3686 + p * (6289 ±0)`
+        Weight::from_parts(4_000_000, 3686)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
             .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 6289).saturating_mul(p.into()))
+    }
+
+    /// Storage: Voting RewardsExpiringAt (r:1 w:1)
+    /// Proof: Voting RewardsExpiringAt (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+    /// Storage: Voting RewardPools (r:50 w:50)
+    /// Proof: Voting RewardPools (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+    /// Storage: Voting OutstandingRewards (r:1 w:1)
+    /// Proof: Voting OutstandingRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting RewardReserve (r:1 w:1)
+    /// Proof: Voting RewardReserve (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+    /// Storage: Voting Proposals (r:50 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: Voting CleanupQueue (r:0 w:50)
+    /// Proof: Voting CleanupQueue (max_values: None, max_size: Some(306), added: 2781, mode: MaxEncodedLen)
+    /// The range of component `p` is `[0, 50]`.
+    fn expire_reward_pools(p: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `4708 + p * (8841 ±0)`
+        Weight::from_parts(5_000_000, 4708)
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 8841).saturating_mul(p.into()))
+    }
+
+    /// Storage: Voting Delegations (r:4 w:1)
+    /// Proof: Voting Delegations (max_values: None, max_size: Some(1777), added: 4252, mode: MaxEncodedLen)
+    /// Storage: Voting Delegators (r:1 w:1)
+    /// Proof: Voting Delegators (max_values: None, max_size: Some(2119), added: 4594, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn delegate() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `28969`
+        Weight::from_parts(42_000_000, 28969)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Voting Delegations (r:1 w:1)
+    /// Proof: Voting Delegations (max_values: None, max_size: Some(1777), added: 4252, mode: MaxEncodedLen)
+    /// Storage: Voting Delegators (r:1 w:1)
+    /// Proof: Voting Delegators (max_values: None, max_size: Some(2119), added: 4594, mode: MaxEncodedLen)
+    /// Storage: Voting VoteLocks (r:1 w:1)
+    /// Proof: Voting VoteLocks (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    fn undelegate() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `13659`
+        Weight::from_parts(31_000_000, 13659)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Storage: Voting Proposals (r:1 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Voting Commitments (r:1 w:1)
+    /// Proof: Voting Commitments (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:1 w:0)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Voting VoteLocks (r:1 w:1)
+    /// Proof: Voting VoteLocks (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn commit_vote() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `22683`
+        Weight::from_parts(49_000_000, 22683)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Voting Proposals (r:1 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Voting Commitments (r:1 w:1)
+    /// Proof: Voting Commitments (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting DelegatedVotes (r:2 w:1)
+    /// Proof: Voting DelegatedVotes (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+    /// Storage: Voting Delegators (r:2 w:0)
+    /// Proof: Voting Delegators (max_values: None, max_size: Some(2119), added: 4594, mode: MaxEncodedLen)
+    /// Storage: Voting Delegations (r:1 w:0)
+    /// Proof: Voting Delegations (max_values: None, max_size: Some(1777), added: 4252, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:1 w:1)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Voting VoteResults (r:1 w:1)
+    /// Proof: Voting VoteResults (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:1 w:1)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    /// The range of component `d` is `[0, 8]`.
+    fn reveal_vote(d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `39274 + d * (4252 ±0)`
+        Weight::from_parts(43_000_000, 39274)
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
+    }
+
+    /// Storage: Voting Proposals (r:1 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: Voting Commitments (r:1 w:1)
+    /// Proof: Voting Commitments (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn reap_commitment() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `12989`
+        Weight::from_parts(30_000_000, 12989)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Voting Proposals (r:1 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:1 w:1)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Voting VoteLocks (r:1 w:1)
+    /// Proof: Voting VoteLocks (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting DelegatedVotes (r:2 w:1)
+    /// Proof: Voting DelegatedVotes (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+    /// Storage: Voting Delegators (r:2 w:0)
+    /// Proof: Voting Delegators (max_values: None, max_size: Some(2119), added: 4594, mode: MaxEncodedLen)
+    /// Storage: Voting Delegations (r:1 w:0)
+    /// Proof: Voting Delegations (max_values: None, max_size: Some(1777), added: 4252, mode: MaxEncodedLen)
+    /// Storage: Voting VoteResults (r:10 w:10)
+    /// Proof: Voting VoteResults (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: Voting Turnout (r:1 w:1)
+    /// Proof: Voting Turnout (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting Ballots (r:0 w:1)
+    /// Proof: Voting Ballots (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:1 w:1)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    /// The range of component `o` is `[1, 10]`.
+    /// The range of component `d` is `[0, 8]`.
+    fn vote_approval(o: u32, d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `43744 + d * (4252 ±0) + o * (2531 ±0)`
+        Weight::from_parts(50_000_000, 43744)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
+            .saturating_add(Weight::from_parts(0, 2531).saturating_mul(o.into()))
+    }
+
+    /// Storage: Voting Proposals (r:1 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:1 w:1)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Voting VoteLocks (r:1 w:1)
+    /// Proof: Voting VoteLocks (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting DelegatedVotes (r:2 w:1)
+    /// Proof: Voting DelegatedVotes (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+    /// Storage: Voting Delegators (r:2 w:0)
+    /// Proof: Voting Delegators (max_values: None, max_size: Some(2119), added: 4594, mode: MaxEncodedLen)
+    /// Storage: Voting Delegations (r:1 w:0)
+    /// Proof: Voting Delegations (max_values: None, max_size: Some(1777), added: 4252, mode: MaxEncodedLen)
+    /// Storage: Voting RankedBallots (r:1 w:1)
+    /// Proof: Voting RankedBallots (max_values: None, max_size: Some(14614), added: 17089, mode: MaxEncodedLen)
+    /// Storage: Voting VoteResults (r:1 w:1)
+    /// Proof: Voting VoteResults (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: Voting Turnout (r:1 w:1)
+    /// Proof: Voting Turnout (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting Ballots (r:0 w:1)
+    /// Proof: Voting Ballots (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:1 w:1)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    /// The range of component `o` is `[1, 10]`.
+    /// The range of component `d` is `[0, 8]`.
+    fn vote_ranked(o: u32, d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `63364 + d * (4252 ±0)`
+        Weight::from_parts(59_000_000, 63364)
+            .saturating_add(Weight::from_parts(450_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
+    }
+
+    /// Storage: Voting RankedBallots (r:1 w:0)
+    /// Proof: Voting RankedBallots (max_values: None, max_size: Some(14614), added: 17089, mode: MaxEncodedLen)
+    /// The range of component `o` is `[2, 10]`.
+    /// The range of component `r` is `[1, 256]`.
+    fn instant_runoff(o: u32, r: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `18079`
+        Weight::from_parts(4_000_000, 18079)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(120_000, 0).saturating_mul(r.into()))
+            .saturating_add(Weight::from_parts(30_000, 0).saturating_mul(o.into()).saturating_mul(r.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+
+    /// Storage: System BlockHash (r:1 w:0)
+    /// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+    /// Storage: Voting SignedVoteNonces (r:64 w:64)
+    /// Proof: Voting SignedVoteNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: Voting Proposals (r:64 w:0)
+    /// Proof: Voting Proposals (max_values: None, max_size: Some(3814), added: 6289, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:4160 w:64)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// Storage: Voting Delegators (r:256 w:0)
+    /// Proof: Voting Delegators (max_values: None, max_size: Some(2119), added: 4594, mode: MaxEncodedLen)
+    /// Storage: Voting Delegations (r:4096 w:0)
+    /// Proof: Voting Delegations (max_values: None, max_size: Some(1777), added: 4252, mode: MaxEncodedLen)
+    /// Storage: Voting DelegatedVotes (r:4096 w:4096)
+    /// Proof: Voting DelegatedVotes (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+    /// Storage: Voting VoteLocks (r:64 w:64)
+    /// Proof: Voting VoteLocks (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:64 w:64)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: System Account (r:64 w:64)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Voting VoteResults (r:64 w:64)
+    /// Proof: Voting VoteResults (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: Voting Turnout (r:64 w:64)
+    /// Proof: Voting Turnout (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:1 w:1)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    /// The range of component `v` is `[1, 64]`.
+    /// The range of component `d` is `[0, 64]`.
+    fn submit_signed_votes(v: u32, d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `6045 + v * (44531 ±0) + v * d * (9439 ±0)`
+        Weight::from_parts(12_000_000, 6045)
+            .saturating_add(Weight::from_parts(95_000_000, 0).saturating_mul(v.into()))
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(v.into()).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(v.into()).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into()).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 44531).saturating_mul(v.into()))
+            .saturating_add(Weight::from_parts(0, 9439).saturating_mul(v.into()).saturating_mul(d.into()))
+    }
+
+    /// Storage: Voting CleanupQueue (r:1 w:1)
+    /// Proof: Voting CleanupQueue (max_values: None, max_size: Some(306), added: 2781, mode: MaxEncodedLen)
+    /// Storage: Voting Votes (r:0 w:1000)
+    /// Proof: Voting Votes (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+    /// The range of component `k` is `[0, 1000]`.
+    fn clear_votes(k: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `3771 + k * (2596 ±0)`
+        Weight::from_parts(7_000_000, 3771)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(k.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+            .saturating_add(Weight::from_parts(0, 2596).saturating_mul(k.into()))
+    }
+
+    /// Storage: Voting CleanupQueue (r:0 w:1)
+    /// Proof: Voting CleanupQueue (max_values: None, max_size: Some(306), added: 2781, mode: MaxEncodedLen)
+    /// Storage: Voting RankedBallots (r:0 w:1)
+    /// Proof: Voting RankedBallots (max_values: None, max_size: Some(14614), added: 17089, mode: MaxEncodedLen)
+    /// Storage: Voting Turnout (r:0 w:1)
+    /// Proof: Voting Turnout (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalEligibility (r:0 w:1)
+    /// Proof: Voting ProposalEligibility (max_values: None, max_size: Some(8215), added: 10690, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalSnapshots (r:0 w:1)
+    /// Proof: Voting ProposalSnapshots (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+    /// Storage: Voting ProposalSeats (r:0 w:1)
+    /// Proof: Voting ProposalSeats (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+    /// Storage: Voting EnactmentTasks (r:1 w:1)
+    /// Proof: Voting EnactmentTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+    /// Storage: Scheduler Agenda (r:1 w:0)
+    /// Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    /// Storage: Voting VetoReasons (r:0 w:1)
+    /// Proof: Voting VetoReasons (max_values: None, max_size: Some(278), added: 2753, mode: MaxEncodedLen)
+    /// Storage: Voting FirstVotes (r:0 w:1)
+    /// Proof: Voting FirstVotes (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+    fn archive_proposal() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `16431`
+        Weight::from_parts(15_000_000, 16431)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+
+    /// Storage: System Number (r:1 w:0)
+    /// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: Voting NativeCheckpoints (r:1 w:1)
+    /// Proof: Voting NativeCheckpoints (max_values: None, max_size: Some(1330), added: 3805, mode: MaxEncodedLen)
+    fn note_balance_change() -> Weight {
+        // Estimated, not benchmarked.
+        // Proof Size summary in bytes:
+        //  Estimated: `5294`
+        Weight::from_parts(9_000_000, 5294)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-    fn create_proposal() -> Weight {
-        Weight::from_parts(16_000_000, 1489)
+    fn create_proposal(a: u32, c: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(24_000_000, 12050)
+            .saturating_add(Weight::from_parts(12_000, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+
+    fn vote(d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(48_000_000, 53617)
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(13_u64))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
+    }
+
+    fn end_proposal() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(78_000_000, 73203)
+            .saturating_add(RocksDbWeight::get().reads(26_u64))
+            .saturating_add(RocksDbWeight::get().writes(14_u64))
+    }
+
+    fn cancel_proposal() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(28_000_000, 15600)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+
+    fn veto_proposal() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(42_000_000, 31046)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+
+    fn claim_reward() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(56_000_000, 28313)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+
+    fn fund_reward() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(42_000_000, 22606)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+
+    fn unlock() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(39_000_000, 11190)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn on_initialize(p: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(6_000_000, 4185)
+            .saturating_add(Weight::from_parts(76_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((25_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((14_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 71714).saturating_mul(p.into()))
+    }
+
+    fn open_proposals(p: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(4_000_000, 3686)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
             .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 6289).saturating_mul(p.into()))
+    }
+
+    fn expire_reward_pools(p: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(5_000_000, 4708)
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 8841).saturating_mul(p.into()))
+    }
+
+    fn delegate() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(42_000_000, 28969)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    fn undelegate() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(31_000_000, 13659)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn commit_vote() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(49_000_000, 22683)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
 
-    fn vote() -> Weight {
-        Weight::from_parts(26_000_000, 4465)
+    fn reveal_vote(d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(43_000_000, 39274)
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
+    }
+
+    fn reap_commitment() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(30_000_000, 12989)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
+    fn vote_approval(o: u32, d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(50_000_000, 43744)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(o.into())))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(o.into())))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
+            .saturating_add(Weight::from_parts(0, 2531).saturating_mul(o.into()))
+    }
 
-    fn end_proposal() -> Weight {
-        Weight::from_parts(36_000_000, 25820)
-            .saturating_add(RocksDbWeight::get().reads(12_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    fn vote_ranked(o: u32, d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(59_000_000, 63364)
+            .saturating_add(Weight::from_parts(450_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(13_u64))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4252).saturating_mul(d.into()))
     }
 
-    fn cancel_proposal() -> Weight {
-        Weight::from_parts(21_000_000, 3465)
+    fn instant_runoff(o: u32, r: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(4_000_000, 18079)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(120_000, 0).saturating_mul(r.into()))
+            .saturating_add(Weight::from_parts(30_000, 0).saturating_mul(o.into()).saturating_mul(r.into()))
             .saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+
+    fn submit_signed_votes(v: u32, d: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(12_000_000, 6045)
+            .saturating_add(Weight::from_parts(95_000_000, 0).saturating_mul(v.into()))
+            .saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(v.into()).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(v.into()).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into()).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 44531).saturating_mul(v.into()))
+            .saturating_add(Weight::from_parts(0, 9439).saturating_mul(v.into()).saturating_mul(d.into()))
+    }
+
+    fn clear_votes(k: u32, ) -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(7_000_000, 3771)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(k.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(k.into())))
+            .saturating_add(Weight::from_parts(0, 2596).saturating_mul(k.into()))
+    }
+
+    fn archive_proposal() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(15_000_000, 16431)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+
+    fn note_balance_change() -> Weight {
+        // Estimated, not benchmarked.
+        Weight::from_parts(9_000_000, 5294)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 }
\ No newline at end of file
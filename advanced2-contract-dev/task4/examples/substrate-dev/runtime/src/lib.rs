@@ -25,10 +25,12 @@ use sp_version::RuntimeVersion;
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
-    construct_runtime, parameter_types,
+    construct_runtime,
+    dispatch::DispatchClass,
+    parameter_types,
     traits::{
-        ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-        StorageInfo,
+        ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOfDiverse, EqualPrivilegeOnly,
+        KeyOwnerProofSystem, Randomness, StorageInfo,
     },
     weights::{
         constants::{
@@ -36,10 +38,12 @@ pub use frame_support::{
         },
         IdentityFee, Weight,
     },
-    StorageValue,
+    PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 pub use pallet_balances::Call as BalancesCall;
+use pallet_balances::WeightInfo as _;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
@@ -48,6 +52,7 @@ pub use sp_runtime::{Perbill, Permill};
 
 /// Import the template pallet.
 pub use pallet_voting;
+use pallet_voting::weights::WeightInfo as _;
 pub use pallet_asset_registry;
 
 /// An index to a block.
@@ -105,10 +110,15 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    // This single bump to 101 covers the whole release: the voting and asset registry storage
+    //   changes applied by `Migrations`, and the changed signatures and new indices of their
+    //   calls. Bump again before any further release.
+    spec_version: 101,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    // Bumped once for the release, covering every changed voting and asset registry call and
+    //   the removal of asset registry call index 5 (`transfer_ownership`).
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -136,16 +146,32 @@ pub fn native_version() -> sp_version::NativeVersion {
 }
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+const MAXIMUM_BLOCK_WEIGHT: Weight =
+    Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX);
 
 parameter_types! {
     pub const BlockHashCount: BlockNumber = 2400;
     pub const Version: RuntimeVersion = VERSION;
     /// We allow for 2 seconds of compute with a 6 second average block time.
+    ///
+    /// Every extrinsic also pays for checkpointing the balance of its signer twice, once when
+    /// the fee is withdrawn and once when the unused part is refunded.
     pub BlockWeights: frame_system::limits::BlockWeights =
-        frame_system::limits::BlockWeights::with_sensible_defaults(
-            Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
-            NORMAL_DISPATCH_RATIO,
-        );
+        frame_system::limits::BlockWeights::builder()
+            .for_class(DispatchClass::all(), |weights| {
+                weights.base_extrinsic = ExtrinsicBaseWeight::get()
+                    .saturating_add(CheckpointedBalancesWeight::note_balance_changes(2));
+            })
+            .for_class(DispatchClass::Normal, |weights| {
+                weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+            })
+            .for_class(DispatchClass::Operational, |weights| {
+                weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
+                weights.reserved =
+                    Some(MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+            })
+            .avg_block_initialization(Perbill::from_percent(10))
+            .build_or_panic();
     pub BlockLength: frame_system::limits::BlockLength = frame_system::limits::BlockLength
         ::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
     pub const SS58Prefix: u8 = 42;
@@ -241,6 +267,46 @@ impl pallet_timestamp::Config for Runtime {
 /// Existential deposit.
 pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
+/// Balances weights plus the voting checkpoints written by `CheckpointedAccountStore`
+pub struct CheckpointedBalancesWeight;
+
+impl CheckpointedBalancesWeight {
+    /// Weight of checkpointing `changes` balances
+    fn note_balance_changes(changes: u64) -> Weight {
+        pallet_voting::weights::SubstrateWeight::<Runtime>::note_balance_change()
+            .saturating_mul(changes)
+    }
+}
+
+impl pallet_balances::WeightInfo for CheckpointedBalancesWeight {
+    fn transfer_allow_death() -> Weight {
+        BalancesWeight::transfer_allow_death().saturating_add(Self::note_balance_changes(2))
+    }
+    fn transfer_keep_alive() -> Weight {
+        BalancesWeight::transfer_keep_alive().saturating_add(Self::note_balance_changes(2))
+    }
+    fn force_set_balance_creating() -> Weight {
+        BalancesWeight::force_set_balance_creating().saturating_add(Self::note_balance_changes(1))
+    }
+    fn force_set_balance_killing() -> Weight {
+        BalancesWeight::force_set_balance_killing().saturating_add(Self::note_balance_changes(1))
+    }
+    fn force_transfer() -> Weight {
+        BalancesWeight::force_transfer().saturating_add(Self::note_balance_changes(2))
+    }
+    fn transfer_all() -> Weight {
+        BalancesWeight::transfer_all().saturating_add(Self::note_balance_changes(2))
+    }
+    fn force_unreserve() -> Weight {
+        BalancesWeight::force_unreserve().saturating_add(Self::note_balance_changes(1))
+    }
+    fn upgrade_accounts(u: u32) -> Weight {
+        BalancesWeight::upgrade_accounts(u).saturating_add(Self::note_balance_changes(u.into()))
+    }
+}
+
+type BalancesWeight = pallet_balances::weights::SubstrateWeight<Runtime>;
+
 impl pallet_balances::Config for Runtime {
     type MaxLocks = ConstU32<50>;
     type MaxReserves = ();
@@ -251,12 +317,12 @@ impl pallet_balances::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DustRemoval = ();
     type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
-    type AccountStore = System;
-    type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+    type AccountStore = pallet_voting::CheckpointedAccountStore<Runtime, System>;
+    type WeightInfo = CheckpointedBalancesWeight;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
-    type MaxHolds = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type MaxHolds = ConstU32<1>;
 }
 
 parameter_types! {
@@ -277,16 +343,215 @@ impl pallet_sudo::Config for Runtime {
     type RuntimeCall = RuntimeCall;
 }
 
+parameter_types! {
+    pub const PreimageBaseDeposit: Balance = 1000 * EXISTENTIAL_DEPOSIT;
+    pub const PreimageByteDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type BaseDeposit = PreimageBaseDeposit;
+    type ByteDeposit = PreimageByteDeposit;
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
+impl pallet_membership::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = EnsureRoot<AccountId>;
+    type RemoveOrigin = EnsureRoot<AccountId>;
+    type SwapOrigin = EnsureRoot<AccountId>;
+    type ResetOrigin = EnsureRoot<AccountId>;
+    type PrimeOrigin = EnsureRoot<AccountId>;
+    type MembershipInitialized = ();
+    type MembershipChanged = ();
+    type MaxMembers = ConstU32<100>;
+    type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+/// Checks voter eligibility against the `Membership` members and `AssetRegistry` balances.
+pub struct VoterEligibility;
+
+impl pallet_voting::VoterEligibility<AccountId> for VoterEligibility {
+    fn is_member(who: &AccountId) -> bool {
+        <Membership as frame_support::traits::SortedMembers<AccountId>>::contains(who)
+    }
+
+    fn asset_balance(asset_id: u32, who: &AccountId) -> u128 {
+        AssetRegistry::approved_balance(asset_id, who)
+    }
+}
+
+impl pallet_asset_registry::OnBalanceChange<AccountId> for VoterEligibility {
+    fn on_balance_change(asset_id: u32, who: &AccountId, previous: u128) {
+        Voting::note_asset_balance_change(asset_id, who, previous)
+    }
+}
+
+parameter_types! {
+    pub const MaxVotesPerAccount: u32 = 32;
+    pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxProposalOptions: u32 = 10;
+    pub const MaxProposalDescriptionLength: u32 = 1000;
+    pub const MaxProposalOptionLength: u32 = 256;
+    pub const MinVotingPeriod: BlockNumber = 10 * MINUTES;
+    pub const MaxVotingPeriod: BlockNumber = 30 * DAYS;
+    pub const MaxStartDelay: BlockNumber = 7 * DAYS;
+    pub const MaxProposalsEndingPerBlock: u32 = 50;
+    pub const MaxProposalsStartingPerBlock: u32 = 50;
+    pub const MinQuorum: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    pub const MinApprovalThreshold: Perbill = Perbill::from_percent(50);
+    pub EnactmentOrigin: OriginCaller = frame_system::RawOrigin::Root.into();
+    pub const EnactmentDelay: BlockNumber = 1 * DAYS;
+    pub const MinEnactmentQuorum: Perbill = Perbill::from_percent(20);
+    pub const MinEnactmentPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxProposalCallLength: u32 = 16 * 1024;
+    pub const MaxDelegators: u32 = 64;
+    pub const MaxDelegationDepth: u32 = 4;
+    pub const RevealPeriod: BlockNumber = 1 * DAYS;
+    pub const CommitDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+    pub const MaxRankings: u32 = 256;
+    pub const MaxAllowlist: u32 = 256;
+    pub const MaxSignedVotes: u32 = 64;
+    pub const MaxVetoReasonLength: u32 = 256;
+    pub const MaxCheckpoints: u32 = 64;
+    pub const MaxVoterHistory: u32 = 256;
+    pub const VotingPalletId: PalletId = PalletId(*b"py/votng");
+    pub const ParticipationReward: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    pub const MinRewardTurnout: Perbill = Perbill::from_percent(1);
+    pub const RewardClaimPeriod: BlockNumber = 30 * DAYS;
+    pub const MaxRewardsExpiringPerBlock: u32 = 50;
+    pub const ProposalDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    pub const MinSupport: Perbill = Perbill::from_percent(10);
+}
+
+/// Generates sr25519 keys and signatures for the signed vote benchmark.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct VotingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_voting::BenchmarkHelper<<Signature as Verify>::Signer, Signature>
+    for VotingBenchmarkHelper
+{
+    fn create_public() -> <Signature as Verify>::Signer {
+        sp_io::crypto::sr25519_generate(sp_core::crypto::key_types::DUMMY, None).into()
+    }
+
+    fn sign(public: &<Signature as Verify>::Signer, message: &[u8]) -> Signature {
+        let sp_runtime::MultiSigner::Sr25519(public) = public else {
+            unreachable!("benchmark keys are sr25519")
+        };
+        sp_io::crypto::sr25519_sign(sp_core::crypto::key_types::DUMMY, public, message)
+            .expect("key was generated by the keystore")
+            .into()
+    }
+}
+
 /// Configure the pallet-voting in pallets/voting.
 impl pallet_voting::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_voting::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type MaxVotesPerAccount = MaxVotesPerAccount;
+    type VoteLockingPeriod = VoteLockingPeriod;
+    type MaxOptions = MaxProposalOptions;
+    type MaxDescriptionLength = MaxProposalDescriptionLength;
+    type MaxOptionLength = MaxProposalOptionLength;
+    type MinVotingPeriod = MinVotingPeriod;
+    type MaxVotingPeriod = MaxVotingPeriod;
+    type MaxStartDelay = MaxStartDelay;
+    type MaxProposalsEndingPerBlock = MaxProposalsEndingPerBlock;
+    type MaxProposalsStartingPerBlock = MaxProposalsStartingPerBlock;
+    type MinQuorum = MinQuorum;
+    type MinApprovalThreshold = MinApprovalThreshold;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type MaxCallLength = MaxProposalCallLength;
+    type EnactmentOrigin = EnactmentOrigin;
+    type EnactmentDelay = EnactmentDelay;
+    type MinEnactmentQuorum = MinEnactmentQuorum;
+    type MinEnactmentPeriod = MinEnactmentPeriod;
+    type MaxDelegators = MaxDelegators;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type RevealPeriod = RevealPeriod;
+    type CommitDeposit = CommitDeposit;
+    type MaxRankings = MaxRankings;
+    type Eligibility = VoterEligibility;
+    type MaxAllowlist = MaxAllowlist;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type MaxSignedVotes = MaxSignedVotes;
+    // Sudo for now, to be replaced by a council collective.
+    type CancelOrigin = EnsureRoot<AccountId>;
+    type VetoOrigin = EnsureRoot<AccountId>;
+    type MaxVetoReasonLength = MaxVetoReasonLength;
+    type MaxCheckpoints = MaxCheckpoints;
+    type MaxVoterHistory = MaxVoterHistory;
+    type PalletId = VotingPalletId;
+    type ParticipationReward = ParticipationReward;
+    type MinRewardTurnout = MinRewardTurnout;
+    type RewardClaimPeriod = RewardClaimPeriod;
+    type MaxRewardsExpiringPerBlock = MaxRewardsExpiringPerBlock;
+    type ProposalDeposit = ProposalDeposit;
+    type MinSupport = MinSupport;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = VotingBenchmarkHelper;
+}
+
+parameter_types! {
+    pub const MaxAssetNameLength: u32 = 100;
+    pub const MaxAssetSymbolLength: u32 = 20;
+    pub const MaxAssetDescriptionLength: u32 = 1000;
+    pub const MaxAssetMetadataLength: u32 = 2000;
+    pub const MaxAssetsPerOwner: u32 = 100;
+    pub const AssetRegistrationDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    pub const AssetRegistrationDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+    pub const AssetRejectionBurn: Perbill = Perbill::from_percent(50);
+    pub const AssetOwnershipTransferPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxAssetReasonLength: u32 = 256;
+    pub const MaxAssetStatusHistory: u32 = 100;
 }
 
 /// Configure the pallet-asset-registry in pallets/asset-registry.
 impl pallet_asset_registry::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_asset_registry::weights::SubstrateWeight<Runtime>;
+    type MaxAssetNameLength = MaxAssetNameLength;
+    type MaxAssetSymbolLength = MaxAssetSymbolLength;
+    type MaxAssetDescriptionLength = MaxAssetDescriptionLength;
+    type MaxAssetMetadataLength = MaxAssetMetadataLength;
+    type MaxAssetsPerOwner = MaxAssetsPerOwner;
+    type OnBalanceChange = VoterEligibility;
+    // Pending assets are moderated by sudo or any member of `Membership`, suspensions need sudo.
+    type ApproveOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<Membership, AccountId>>;
+    type RejectOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<Membership, AccountId>>;
+    type SuspendOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RegistrationDeposit = AssetRegistrationDeposit;
+    type RegistrationDepositPerByte = AssetRegistrationDepositPerByte;
+    type RejectionBurn = AssetRejectionBurn;
+    type OwnershipTransferPeriod = AssetOwnershipTransferPeriod;
+    type MaxReasonLength = MaxAssetReasonLength;
+    type MaxStatusHistory = MaxAssetStatusHistory;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -303,6 +568,9 @@ construct_runtime!(
         Balances: pallet_balances,
         TransactionPayment: pallet_transaction_payment,
         Sudo: pallet_sudo,
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler,
+        Membership: pallet_membership,
         // Include the custom logic from the pallet-voting in the runtime.
         Voting: pallet_voting,
         // Include the custom logic from the pallet-asset-registry in the runtime.
@@ -333,6 +601,14 @@ pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+    pallet_voting::migrations::v1::MigrateToV1<Runtime>,
+    pallet_voting::migrations::v2::MigrateToV2<Runtime>,
+    pallet_asset_registry::migrations::v1::MigrateToV1<Runtime>,
+    pallet_asset_registry::migrations::v2::MigrateToV2<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -340,6 +616,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -503,6 +780,46 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_voting_runtime_api::VotingApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+        pallet_voting::BoundedCallOf<Runtime>,
+        pallet_voting::DescriptionOf<Runtime>,
+        pallet_voting::OptionsOf<Runtime>,
+    > for Runtime {
+        fn proposal(proposal_id: u32) -> Option<pallet_voting::ProposalInfoOf<Runtime>> {
+            Voting::get_proposal(proposal_id)
+        }
+        fn results(proposal_id: u32) -> Vec<Balance> {
+            Voting::get_vote_results(proposal_id)
+        }
+        fn active_proposals(
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, pallet_voting::ProposalInfoOf<Runtime>)> {
+            Voting::active_proposals(start, limit)
+        }
+        fn has_voted(proposal_id: u32, who: AccountId) -> bool {
+            Voting::has_voted(proposal_id, &who)
+        }
+        fn voter_history(who: AccountId, page: u32) -> Vec<u32> {
+            Voting::voter_history(&who, page)
+        }
+    }
+
+    impl pallet_asset_registry_runtime_api::AssetRegistryApi<
+        Block,
+        AccountId,
+        BlockNumber,
+        pallet_asset_registry::ReasonOf<Runtime>,
+    > for Runtime {
+        fn status_history(asset_id: u32) -> Vec<pallet_asset_registry::StatusChangeOf<Runtime>> {
+            AssetRegistry::status_history(asset_id).into_inner()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
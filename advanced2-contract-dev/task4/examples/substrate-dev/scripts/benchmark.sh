@@ -0,0 +1,26 @@
+#!/bin/bash
+
+# Substrate Development Benchmark Script
+# This script benchmarks the custom pallets and writes their weights.rs files
+
+set -e
+
+BINARY="./target/release/substrate-dev-node"
+
+echo "🔨 Building the node with runtime benchmarks..."
+cargo build --release --features runtime-benchmarks
+
+for pallet in voting asset-registry; do
+    echo "⏱️  Benchmarking pallet_${pallet//-/_}..."
+    "$BINARY" benchmark pallet \
+        --chain=dev \
+        --steps=50 \
+        --repeat=20 \
+        --pallet="pallet_${pallet//-/_}" \
+        --extrinsic='*' \
+        --wasm-execution=compiled \
+        --heap-pages=4096 \
+        --output="./pallets/${pallet}/src/weights.rs"
+done
+
+echo "✅ Weights written to pallets/*/src/weights.rs"